@@ -1 +1 @@
->[-]+<[-]>[<+>-]>[-]>[-]<>+++++[<+++++>-]<+>[-]<<[-]>>>[-]>[-]<<<<<[>>>+<<+<-]>[<+>-][-]>>[<[<+>>>+<<-]>>[<<+>>-]+<<<[>>>-<<-<[-]]>>>[>+<[-]]<-]>>[<<+>>-]<<[<<+>>-]<[<+>-]<[>>[-]>[-]<<<<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]<<[-]>[<+>-][-]++++++++++.[-]>[-]<<[>+>+<<-]>>[<<+>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<[>+<-]>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<[-]++++++++++++++++++++++++++++++++++++++++++++++++++++++++++.--------------------------.[-]>[-]<+[>>>[-]<[-]>[<+>-][-]>[-]<<<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-][-]+>[-]>[-]>[-]<<<<[>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<-]>>>>[<<<<+>>>>-]<<<<[>>>+<<<-]>[>>+<<-]>+>[<->[-]]<<<[-]+>>[<<-<<<[-]>+>>>>[-]]<<[>>>[-]>[-]<<<<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]<<<[-]>>[<<+>>-][-]>[-]<+[>>[-]>[-]<<<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-][-]>[-]>[-]>[-]<<<<[>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<-]>>>>[<<<<+>>>>-]<<<<[>>>+<<<-]>[>>+<<-]>+>[<->[-]]<<<[-]+>>[<<-<<[-]>+>>>[-]]<<[>[-]>>[-]<<<<<<<[>>>>>+>>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-][-]+>[-]>[-]>[-]<<<<<[>>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<<-]>>>>>[<<<<<+>>>>>-]<<<<<[>>>>+<<<<-]>>[>>+<<-]>+>[<->[-]]<<<<[-]+>>>[<<<-<<<[-]>+>>>>>[-]]<<<[>>[-]>>[-]<<<<<<<<<[>>>>>>>+>>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-][-]++[<<->>-]<<<<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]<<[-]]<[-]]<<]>>[-]>[-]>[-]>[-]+<<<<[>>>>[<<+<+>>>-]<<<[>>>+<<<-]+>[<->>>-<<[-]]<[>>+<<[-]]<-]>>>[<<<+>>>-]<<<[>>+<<-]>>>>[<<+>>-]<<<+>[<->[-]]<[>>>>[-]>[-]<<<<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]<<<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]<<<<[-]]<<[-]>[-]<<<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-][-]>[-]>[-]>[-]<<<<[>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<-]>>>>[<<<<+>>>>-]<<<<[>>>+<<<-]>[>>+<<-]>+>[<->[-]]<<<[-]+>>[<<->[-]>>[-]<<<<<<<<<<[>>>>>>>>+>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]++>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<[>>>+>>>>>>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]<<<<<<<<[>>>+>>>>>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]<<<<<<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>>>>>>>[-]<<<[>>>>+<+<<<-]>>>[<<<+>>>-]<<<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]<<<<<<<<<<<[-]>>[-]<<<<<<<<<<[>>>>>>>>+>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<<[>>+<<-]>>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<<[-]++++++++++++++++++++++++++++++++.>[-]]<<[>[-]>>[-]<<<<<<<[>>>>>+>>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-][-]+>[-]>[-]>[-]<<<<<[>>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<<-]>>>>>[<<<<<+>>>>>-]<<<<<[>>>>+<<<<-]>>[>>+<<-]>+>[<->[-]]<<<<[-]+>>>[<<<->>[-]+++>>[-]>[-]<<<<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-][-]>[-]<<<<[>>[>>+<+<-]>[<+>-]<<<-]>>[-]<<[-]+>>[-]<<[>>>>+<<+<<-]>>[<<+>>-]<<<<<<<<<<<<[-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]<<<<[-]>>[-]<<<<<<<<<<<<[>>>>>>>>>>+>>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<<[>>+<<-]>>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<<[-]++++++++++++++++++++++++++++++++.>[-]]<<<[>>[-]>>[-]<<<<<<<<<<<<[>>>>>>>>>>+>>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-][-]++>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<[>>>+>>>>>>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]<<<<<<<<[>>>+>>>>>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]<<<<<<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>>>>>>>[-]<<<[>>>>+<+<<<-]>>>[<<<+>>>-]<<<<<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]<<<<<<<<<<<[-]>>[-]<<<<<<<<<<<<[>>>>>>>>>>+>>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<<[>>+<<-]>>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<<[-]++++++++++++++++++++++++++++++++.<<[-]]<[-]]<<<[-]]<<<]>>>[-]>>[-]>[-]>[-]+<<<<<<[>>>>>>[<<+<<+>>>>-]<<<<[>>>>+<<<<-]+>>[<<->>>>-<<[-]]<<[>>>+<<<[-]]<<-]>>>>>[<<<<<+>>>>>-]<<<<<[>>>>+<<<<-]>>>>>>[<<+>>-]<<<<+>>[<<->>[-]]<<[[-]]<<<<<<+>>>[-]>[-]<>+++++[<+++++>-]<+>[-]<<<[-]>>>>>[-]>>[-]<<<<<<<<[>>>>+<<<+<-]>[<+>-][-]>>>[<[<<+>>>>>+<<<-]>>>[<<<+>>>-]+<<<<<[>>>>>-<<<-<<[-]]>>>>>[>>+<<[-]]<<-]>>>>[<<<<+>>>>-]<<<<[<<<+>>>-]<[<<+>>-]<<][-]++++++++++.
+>>>>[-]+<<<<[-]>>>>[<<<<+>>>>-]>[-]>[-]<>+++++[<+++++>-]<+>[-]<<[-]>>>[-]>[-]<<<<<<<<[>>>>>>+<<+<<<<-]>>>>[<<<<+>>>>-][-]>>[<[<+>>>+<<-]>>[<<+>>-]+<<<[>>>-<<-<[-]]>>>[>+<[-]]<-]>>[<<+>>-]<<[<<+>>-]<[<+>-]<[>[-]>[-]<<<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]<<<<<[-]>>>>[<<<<+>>>>-][-]++++++++++.[-]>[-]<<<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<[>+<-]>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<[-]++++++++++++++++++++++++++++++++++++++++++++++++++++++++++.--------------------------.[-]>[-]<+[>>[-]<<<<<[-]>>>>>[<<<<<+>>>>>-][-]>[-]<<<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-][-]+>[-]>[-]>[-]<<<<[>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<-]>>>>[<<<<+>>>>-]<<<<[>>>+<<<-]>[>>+<<-]>+>[<->[-]]<<<[-]+>>[<<-<<[-]>+>>>[-]]<<[>[-]>>[-]<<<<<<<<<[>>>>>>>+>>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]<<<<<<<[-]>>>>>[<<<<<+>>>>>-][-]>>[-]<<+[>>>[-]>[-]<<<<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-][-]>[-]>[-]>[-]<<<<[>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<-]>>>>[<<<<+>>>>-]<<<<[>>>+<<<-]>[>>+<<-]>+>[<->[-]]<<<[-]+>>[<<-<<<[-]>>+>>>[-]]<<[>[-]>>[-]<<<<<<<<<<<[>>>>>>>>>+>>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-][-]+>[-]>[-]>[-]<<<<<[>>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<<-]>>>>>[<<<<<+>>>>>-]<<<<<[>>>>+<<<<-]>>[>>+<<-]>+>[<->[-]]<<<<[-]+>>>[<<<-<<<<[-]>>+>>>>>[-]]<<<[>>[-]>>[-]<<<<<<<<<<<<<[>>>>>>>>>>>+>>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-][-]++[<<->>-]<<<<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]<<[-]]<[-]]<<<]>>>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]<<+>[<[<<+<+>>>-]<<<[>>>+<<<-]+>[<->>>-<<[-]]<[>>+<<[-]]>>>>-]<<[>>+<<-]>>[<<<+>>>-]<[<<+>>-]<<<+>[<->[-]]<[>>>>>[-]>[-]<<<<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]<<<<<[-]]<<<[-]>>[-]<<<<<<<<[>>>>>>+>>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-][-]>[-]>[-]>[-]<<<<<[>>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<<-]>>>>>[<<<<<+>>>>>-]<<<<<[>>>>+<<<<-]>>[>>+<<-]>+>[<->[-]]<<<<[-]+>>>[<<<->>[-]>>[-]<<<<<<<<<<<[>>>>>>>>>+>>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-][-]++>>>>>>>>[-]<<<<<<<[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<[>>>+>>>>>>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]<<<<<<<<[>>>+>>>>>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]<<<<+<<<[->+>-[>+>>]>[[-<+>]+>+>>]<<<<<<]>>>->>>>>[-]<[-]<<<[>>>>+<+<<<-]>>>[<<<+>>>-]<<<<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]<<<<<<<<<<<[-]>>[-]<<<<<<<<<<<[>>>>>>>>>+>>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<<[>>+<<-]>>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<<[-]++++++++++++++++++++++++++++++++.>[-]]<<<[>>[-]>>[-]<<<<<<<<<<[>>>>>>>>+>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]+>[-]>[-]>[-]<<<<<[>>[>>+<+<-]>[<+>-]+>[<-<->>[-]]<[>>+<<[-]]<<<-]>>>>>[<<<<<+>>>>>-]<<<<<[>>>>+<<<<-]>>[>>+<<-]>+>[<->[-]]<<<<[-]+>>>[<<<->>[-]+++>>[-]>[-]<<<<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-][-]>[-]<<<<[>>[>>+<+<-]>[<+>-]<<<-]>>[-]<<[-]+>>[-]<<[>>>>+<<+<<-]>>[<<+>>-]<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>-]<<<<[-]>>[-]<<<<<<<<<<<<<[>>>>>>>>>>>+>>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<<[>>+<<-]>>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<<[-]++++++++++++++++++++++++++++++++.>[-]]<<<[>>[-]>>[-]<<<<<<<<<<<<<[>>>>>>>>>>>+>>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-][-]++>>>>>>>>[-]<<<<<<<[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<[>>>+>>>>>>>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-][-]<<<<<<<<[>>>+>>>>>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]<<<<+<<<[->+>-[>+>>]>[[-<+>]+>+>>]<<<<<<]>>>->>>>>[-]<[-]<<<[>>>>+<+<<<-]>>>[<<<+>>>-]<<<<<<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>-]<<<<<<<<<<<[-]>>[-]<<<<<<<<<<<<<[>>>>>>>>>>>+>>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-][-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<<<<<<<<<<<[>>+<<-]>>[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<<<[-]++++++++++++++++++++++++++++++++.<<[-]]<<[-]]<[-]]<<]>>[-]>[-]>[-]>[-]>[-]>[-]<<<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]<<+>[<[<<+<+>>>-]<<<[>>>+<<<-]+>[<->>>-<<[-]]<[>>+<<[-]]>>>>-]<<[>>+<<-]>>[<<<+>>>-]<[<<+>>-]<<<+>[<->[-]]<[[-]]<<<<<<<+>>>>>[-]>[-]<>+++++[<+++++>-]<+>[-]<<[-]>>>[-]>[-]<<<<<<<<[>>>>>>+<<+<<<<-]>>>>[<<<<+>>>>-][-]>>[<[<+>>>+<<-]>>[<<+>>-]+<<<[>>>-<<-<[-]]>>>[>+<[-]]<-]>>[<<+>>-]<<[<<+>>-]<[<+>-]<][-]++++++++++.
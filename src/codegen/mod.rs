@@ -20,6 +20,6 @@
  
 mod quads;
 
-pub use self::quads::BFQuad;
+pub use self::quads::{BFQuad, STRING_CAPACITY, STRING_SCRATCH};
 pub use self::quads::resolve;
 pub use self::quads::create_string;
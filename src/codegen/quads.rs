@@ -17,8 +17,15 @@
 // along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
 // 
 // < end copyright > 
+use std::cmp;
  
 
+// the most characters a string can hold
+pub const STRING_CAPACITY: u32 = 64;
+
+// how many scratch cells the string routines need
+pub const STRING_SCRATCH: u32 = 27;
+
 #[derive(Debug)]
 pub enum BFQuad {
     To( u32 ),
@@ -61,6 +68,23 @@ pub enum BFQuad {
     GetArray( u32, u32,
               u32 ),
 
+    // strings are arrays with the length in element 0 and the
+    // characters after it. The last argument of each string
+    // routine is the first of a run of STRING_SCRATCH cells.
+    StrLen( u32, u32, u32 ),
+    StrChar( u32, u32, u32, u32 ),
+    StrAsc( u32, u32, u32 ),
+    StrClear( u32, u32 ),
+    StrPush( u32, u32, u32 ),
+    StrAppend( u32, u32, u32 ),
+    StrSlice( u32, u32, u32, u32, u32 ),
+    StrPrint( u32, u32 ),
+    StrNum( u32, u32, u32, u32 ),
+    StrVal( u32, u32, u32, u32 ),
+    PrintNum( u32, u32, u32 ),
+    PrintNumCol( u32, u32, u32, u32 ),
+    PrintField( u32, u32, u32, u32, Option<u32>, u32 ),
+
     RawBF( &'static str ),
     RawBFStr( String ),
     Comment ( String )
@@ -326,8 +350,14 @@ fn emit_step(quad: BFQuad, comment: bool) -> Vec<BFQuad> {
             assert!(t3 - res == 1);
             assert!(t4 - t3 == 1); // TODO how many temps are needed?
 
+            // rem counts one more than the remainder, so that it is
+            // never zero when div runs out, even for a divisor of one
+            vec.push(BFQuad::To(rem));
+            vec.push(BFQuad::RawBF("+"));
             vec.push(BFQuad::To(quo));
-            vec.push(BFQuad::RawBF("[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]"));
+            vec.push(BFQuad::RawBF("[->+>-[>+>>]>[[-<+>]+>+>>]<<<<<<]"));
+            vec.push(BFQuad::To(rem));
+            vec.push(BFQuad::RawBF("-"));
 
             /*
             vec.push(BFQuad::Move(div, t4));
@@ -529,6 +559,347 @@ fn emit_step(quad: BFQuad, comment: bool) -> Vec<BFQuad> {
             }
         },
 
+        BFQuad::StrLen(s, d, t) => {
+            // adds the length of s to d
+            vec.push(BFQuad::Zero(t));
+            vec.push(BFQuad::GetArray(s, t, d));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strlen: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrChar(s, pos, d, t) => {
+            // adds the character of s at pos (starting from 1) to d
+            vec.push(BFQuad::Zero(t));
+            vec.push(BFQuad::Zero(t+1));
+            vec.push(BFQuad::AddTo(pos, t, t+1));
+            vec.push(BFQuad::GetArray(s, t, d));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strchar: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrAsc(s, d, t) => {
+            // adds the first character of s to d, or nothing if s
+            // is empty
+            let (len, pos) = (t, t+1);
+            vec.push(BFQuad::Zero(len));
+            vec.push(BFQuad::Zero(pos));
+            vec.push(BFQuad::StrLen(s, len, t+2));
+            vec.push(BFQuad::If(len));
+            vec.push(to(pos));
+            vec.push(BFQuad::RawBF("+"));
+            vec.push(BFQuad::StrChar(s, pos, d, t+2));
+            vec.push(BFQuad::Zero(pos));
+            vec.push(BFQuad::EndIf(len));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strasc: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrClear(s, t) => {
+            vec.push(BFQuad::Zero(t));
+            vec.push(BFQuad::Zero(t+1));
+            vec.push(BFQuad::SetArray(s, t, t+1));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strclear: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrPush(s, c, t) => {
+            // adds the character c to the end of s, consuming c.
+            // characters past the capacity of s are dropped.
+            let (len, len_copy, cap, room) = (t, t+1, t+2, t+3);
+            let (t1, t2, idx) = (t+4, t+5, t+6);
+            for i in t..t+7 {
+                vec.push(BFQuad::Zero(i));
+            }
+            
+            vec.push(BFQuad::StrLen(s, len, idx));
+            vec.push(BFQuad::AddTo(len, len_copy, t1));
+            vec.push(to(cap));
+            vec.push(BFQuad::Constant(STRING_CAPACITY));
+            vec.push(BFQuad::Less(len_copy, cap, room, t1, t2));
+            
+            vec.push(BFQuad::If(room));
+            vec.push(to(len));
+            vec.push(BFQuad::RawBF("+"));
+            vec.push(BFQuad::AddTo(len, idx, t1));
+            vec.push(BFQuad::SetArray(s, idx, c));
+            // idx is now zero, so this sets the length
+            vec.push(BFQuad::SetArray(s, idx, len));
+            vec.push(BFQuad::EndIf(room));
+            
+            vec.push(BFQuad::Zero(c));
+            vec.push(BFQuad::Zero(len));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strpush: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrAppend(dest, src, t) => {
+            // adds every character of src to the end of dest
+            let (count, pos, chr) = (t, t+1, t+2);
+            for i in t..t+3 {
+                vec.push(BFQuad::Zero(i));
+            }
+
+            vec.push(BFQuad::StrLen(src, count, t+3));
+            vec.push(BFQuad::For(count));
+            vec.push(to(pos));
+            vec.push(BFQuad::RawBF("+"));
+            vec.push(BFQuad::StrChar(src, pos, chr, t+3));
+            vec.push(BFQuad::StrPush(dest, chr, t+5));
+            vec.push(BFQuad::Next(count));
+            vec.push(BFQuad::Zero(pos));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strappend: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrSlice(dest, src, from, count, t) => {
+            // adds count characters of src, starting at from (which
+            // must be at least 1), to the end of dest. Positions past
+            // the end of src are skipped. Consumes from and count.
+            let (len, a, b, ok, t1, t2, chr) =
+                (t, t+1, t+2, t+3, t+4, t+5, t+6);
+            for i in t..t+7 {
+                vec.push(BFQuad::Zero(i));
+            }
+
+            vec.push(BFQuad::StrLen(src, len, t+7));
+            vec.push(BFQuad::For(count));
+            vec.push(BFQuad::AddTo(from, a, t1));
+            vec.push(BFQuad::AddTo(len, b, t1));
+            vec.push(BFQuad::LessOrEqual(a, b, ok, t1, t2));
+            vec.push(BFQuad::If(ok));
+            vec.push(BFQuad::StrChar(src, from, chr, t+7));
+            vec.push(BFQuad::StrPush(dest, chr, t+9));
+            vec.push(BFQuad::EndIf(ok));
+            vec.push(to(from));
+            vec.push(BFQuad::RawBF("+"));
+            vec.push(BFQuad::Next(count));
+            vec.push(BFQuad::Zero(from));
+            vec.push(BFQuad::Zero(len));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strslice: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrPrint(s, t) => {
+            let (count, pos, chr) = (t, t+1, t+2);
+            for i in t..t+3 {
+                vec.push(BFQuad::Zero(i));
+            }
+
+            vec.push(BFQuad::StrLen(s, count, t+3));
+            vec.push(BFQuad::For(count));
+            vec.push(to(pos));
+            vec.push(BFQuad::RawBF("+"));
+            vec.push(BFQuad::StrChar(s, pos, chr, t+3));
+            vec.push(to(chr));
+            vec.push(BFQuad::RawBF("."));
+            vec.push(BFQuad::Zero(chr));
+            vec.push(BFQuad::Next(count));
+            vec.push(BFQuad::Zero(pos));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strprint: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrNum(dest, n, decimals, t) => {
+            // adds the decimal digits of n to the end of dest,
+            // consuming n.
+            decimal_digits(&mut vec, n, decimals, t,
+                           &|c| vec![BFQuad::StrPush(dest, c, t+20)]);
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strnum: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::PrintNum(n, decimals, t) => {
+            decimal_digits(&mut vec, n, decimals, t,
+                           &|c| vec![to(c),
+                                     BFQuad::RawBF("."),
+                                     BFQuad::Zero(c)]);
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("printnum: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::PrintNumCol(n, decimals, col, t) => {
+            // like PrintNum, but also adds the number of characters
+            // printed to col
+            decimal_digits(&mut vec, n, decimals, t,
+                           &|c| vec![to(c),
+                                     BFQuad::RawBF("."),
+                                     BFQuad::Zero(c),
+                                     to(col),
+                                     BFQuad::RawBF("+")]);
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("printnumcol: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::PrintField(n, width, zeros, places, col, t) => {
+            // prints n right-aligned in a field of width digits
+            // before the point, the last zeros of them padded with
+            // zeros instead of spaces. Adds the number of characters
+            // printed to col, if given.
+            field_digits(&mut vec, n, width, zeros, places, t,
+                         &|c| {
+                             let mut v = vec![to(c),
+                                              BFQuad::RawBF("."),
+                                              BFQuad::Zero(c)];
+                             if let Some(col) = col {
+                                 v.push(to(col));
+                                 v.push(BFQuad::RawBF("+"));
+                             }
+                             v
+                         });
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("printfield: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
+        BFQuad::StrVal(s, d, decimals, t) => {
+            // adds the number at the start of s to d, keeping the
+            // given number of decimal places. Spaces are skipped, and
+            // anything else that is not part of the number ends it.
+            let (count, pos, chr, stop, go) = (t, t+1, t+2, t+3, t+4);
+            let (c1, c2, k, is_digit, lo, hi) = (t+5, t+6, t+7, t+8, t+9, t+10);
+            let (t1, t2, space, not_digit) = (t+11, t+12, t+13, t+14);
+            let (seen_point, frac_left, point, take, not_point) =
+                (t+15, t+16, t+17, t+18, t+19);
+            for i in t..t+20 {
+                vec.push(BFQuad::Zero(i));
+            }
+
+            vec.push(to(frac_left));
+            vec.push(BFQuad::Constant(decimals));
+            vec.push(BFQuad::StrLen(s, count, t+20));
+            vec.push(BFQuad::For(count));
+            vec.push(to(pos));
+            vec.push(BFQuad::RawBF("+"));
+            vec.push(BFQuad::StrChar(s, pos, chr, t+20));
+            
+            vec.push(BFQuad::AddTo(stop, c1, t1));
+            vec.push(BFQuad::Not(c1, go));
+            vec.push(BFQuad::If(go));
+
+            // is_digit = chr >= '0' and chr <= '9'
+            vec.push(BFQuad::AddTo(chr, c1, t1));
+            vec.push(to(k));
+            vec.push(BFQuad::Constant(48));
+            vec.push(BFQuad::GreaterOrEqual(c1, k, lo, t1, t2));
+            vec.push(BFQuad::AddTo(chr, c2, t1));
+            vec.push(to(k));
+            vec.push(BFQuad::Constant(57));
+            vec.push(BFQuad::LessOrEqual(c2, k, hi, t1, t2));
+            vec.push(BFQuad::If(lo));
+            vec.push(BFQuad::Move(hi, is_digit));
+            vec.push(BFQuad::EndIf(lo));
+            vec.push(BFQuad::Zero(hi));
+
+            vec.push(BFQuad::AddTo(chr, c1, t1));
+            vec.push(to(k));
+            vec.push(BFQuad::Constant(32));
+            vec.push(BFQuad::Equal(c1, k, space, t1, t2));
+            vec.push(BFQuad::AddTo(chr, c1, t1));
+            vec.push(to(k));
+            vec.push(BFQuad::Constant(46));
+            vec.push(BFQuad::Equal(c1, k, point, t1, t2));
+
+            vec.push(BFQuad::IfElse(is_digit, not_digit));
+            // digits past the last decimal place are ignored
+            vec.push(BFQuad::AddTo(seen_point, c1, t1));
+            vec.push(BFQuad::Not(c1, take));
+            vec.push(BFQuad::AddTo(frac_left, take, t1));
+            vec.push(BFQuad::If(take));
+            // d = d * 10 + chr - '0'
+            vec.push(BFQuad::For(d));
+            vec.push(to(c2));
+            vec.push(BFQuad::Constant(10));
+            vec.push(BFQuad::Next(d));
+            vec.push(BFQuad::Move(c2, d));
+            vec.push(to(chr));
+            vec.push(BFQuad::SubConstant(48));
+            vec.push(BFQuad::Move(chr, d));
+            vec.push(BFQuad::AddTo(seen_point, c1, t1));
+            vec.push(BFQuad::If(c1));
+            vec.push(to(frac_left));
+            vec.push(BFQuad::RawBF("-"));
+            vec.push(BFQuad::EndIf(c1));
+            vec.push(BFQuad::EndIf(take));
+            vec.push(BFQuad::Else(is_digit, not_digit));
+            
+            vec.push(BFQuad::IfElse(point, not_point));
+            if decimals == 0 {
+                vec.push(to(stop));
+                vec.push(BFQuad::RawBF("+"));
+            } else {
+                // the first point starts the fraction, a second
+                // one ends the number
+                vec.push(BFQuad::AddTo(seen_point, stop, t1));
+                vec.push(BFQuad::Zero(seen_point));
+                vec.push(BFQuad::RawBF("+"));
+            }
+            vec.push(BFQuad::Else(point, not_point));
+            vec.push(BFQuad::Not(space, c1));
+            vec.push(BFQuad::Move(c1, stop));
+            vec.push(BFQuad::EndElse(not_point));
+            
+            vec.push(BFQuad::EndElse(not_digit));
+            vec.push(BFQuad::Zero(space));
+            vec.push(BFQuad::Zero(point));
+            
+            vec.push(BFQuad::EndIf(go));
+            vec.push(BFQuad::Zero(chr));
+            vec.push(BFQuad::Next(count));
+
+            // fill in any decimal places that were not given
+            vec.push(BFQuad::For(frac_left));
+            vec.push(BFQuad::For(d));
+            vec.push(to(c2));
+            vec.push(BFQuad::Constant(10));
+            vec.push(BFQuad::Next(d));
+            vec.push(BFQuad::Move(c2, d));
+            vec.push(BFQuad::Next(frac_left));
+            
+            vec.push(BFQuad::Zero(pos));
+            vec.push(BFQuad::Zero(stop));
+            vec.push(BFQuad::Zero(seen_point));
+
+            if comment {
+                vec.insert(0, BFQuad::RawBF("strval: "));
+                vec.push(BFQuad::RawBF("\n"));
+            }
+        },
+
         BFQuad::To(_) => {
             vec.push(quad);
 
@@ -557,6 +928,90 @@ fn emit_step(quad: BFQuad, comment: bool) -> Vec<BFQuad> {
     return vec;
 }
 
+// splits n into decimal digits, consuming n, and emits code for
+// each digit character (and the decimal point, if there are any
+// decimal places) with emit_char. Leading zeros are skipped, but
+// there is always a digit before the point. Cells hold at most
+// 10 digits.
+fn decimal_digits(vec: &mut Vec<BFQuad>, n: u32, decimals: u32, t: u32,
+                  emit_char: &dyn Fn(u32) -> Vec<BFQuad>) {
+    field_digits(vec, n, 1, 1, decimals, t, emit_char);
+}
+
+// like decimal_digits, but pads the part before the point to width
+// characters: with zeros for the last zeros positions and spaces
+// before them. Numbers too wide for the field are printed in full.
+// Padding with spaces needs 2 more scratch cells, at t+20.
+fn field_digits(vec: &mut Vec<BFQuad>, n: u32, width: u32, zeros: u32,
+                places: u32, t: u32,
+                emit_char: &dyn Fn(u32) -> Vec<BFQuad>) {
+    let digits = t+7;
+    let (started, show, tmp) = (t+17, t+18, t+19);
+    let (blank, space) = (t+20, t+21);
+    let zeros = cmp::max(zeros, 1);
+    let padded = width > zeros;
+    let scratch = if padded { 22 } else { 20 };
+    for i in t..t+scratch {
+        vec.push(BFQuad::Zero(i));
+    }
+
+    // peel off the digits, least significant first
+    for k in 0..10 {
+        vec.push(BFQuad::Move(n, t));
+        vec.push(to(t+2));
+        vec.push(BFQuad::Constant(10));
+        vec.push(BFQuad::Div(t, t+1, t+2, t+3, t+4, t+5, t+6));
+        vec.push(BFQuad::Move(t+3, digits + k));
+        vec.push(BFQuad::Move(t+4, n));
+        vec.push(BFQuad::Zero(t+1));
+        vec.push(BFQuad::Zero(t+2));
+    }
+
+    // field positions past the last digit a cell can hold are
+    // always padding
+    for k in (10..places + width).rev() {
+        vec.push(to(tmp));
+        vec.push(BFQuad::Constant(if k < places + zeros { 48 } else { 32 }));
+        vec.extend(emit_char(tmp));
+    }
+
+    // then emit them, most significant first
+    let forced = cmp::min(places + zeros - 1, 9);
+    for k in (0..10).rev() {
+        if k == forced {
+            vec.push(to(started));
+            vec.push(BFQuad::RawBF("+"));
+        } else {
+            vec.push(BFQuad::AddTo(digits + k, started, tmp));
+        }
+
+        if places > 0 && k + 1 == places {
+            vec.push(to(tmp));
+            vec.push(BFQuad::Constant(46));
+            vec.extend(emit_char(tmp));
+        }
+
+        if padded && k > forced && k < places + width {
+            vec.push(BFQuad::AddTo(started, show, tmp));
+            vec.push(BFQuad::Not(show, blank));
+            vec.push(BFQuad::If(blank));
+            vec.push(to(space));
+            vec.push(BFQuad::Constant(32));
+            vec.extend(emit_char(space));
+            vec.push(BFQuad::EndIf(blank));
+        }
+
+        vec.push(BFQuad::AddTo(started, show, tmp));
+        vec.push(BFQuad::If(show));
+        vec.push(to(digits + k));
+        vec.push(BFQuad::Constant(48));
+        vec.extend(emit_char(digits + k));
+        vec.push(BFQuad::EndIf(show));
+        vec.push(BFQuad::Zero(digits + k));
+    }
+    vec.push(BFQuad::Zero(started));
+}
+
 fn resolve_tos(quads: Vec<BFQuad>) -> Vec<BFQuad> {
     let mut vec = Vec::new();
     let mut curr = 0;
@@ -18,34 +18,219 @@
 // 
 // < end copyright > 
  
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
 use parser;
+use parser::structs::DBCommand;
 use ir;
+use ir::Block;
 use codegen;
+use serde_json;
 
-
+// compiles with the default options
+#[cfg(test)]
 pub fn compile(prgm: String, semcom: bool, ircom: bool, opts: bool) 
                -> String
+{
+    return compile_with(prgm, semcom, ircom, opts, &ir::Options::default());
+}
+
+pub fn compile_with(prgm: String, semcom: bool, ircom: bool, opts: bool,
+                    options: &ir::Options) -> String
+{
+    return emit_stages(prgm, semcom, ircom, opts, options, &["bf"]);
+}
+
+// compiles a program, returning the error instead of panicking
+// when it can't be compiled
+pub fn try_compile_with(prgm: String, opts: bool, options: &ir::Options)
+                        -> Result<String, String>
+{
+    return catch_panic(|| compile_with(prgm, false, false, opts, options));
+}
+
+// compiles commands that were already parsed, returning the error
+// instead of panicking when they can't be compiled
+pub fn try_compile_commands(parsed: Vec<DBCommand>, opts: bool,
+                            options: &ir::Options) -> Result<String, String>
+{
+    return catch_panic(|| {
+        emit_parsed_stages(parsed, false, false, opts, options, &["bf"])
+    });
+}
+
+thread_local! {
+    // set while catch_panic runs, so the panics it catches aren't
+    // printed. A const initializer would need a newer Rust.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static CATCHING: Cell<bool> = Cell::new(false);
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+// runs f, returning the message it panicked with instead of
+// panicking. Panics anywhere else are printed as usual.
+pub fn catch_panic<R, F: FnOnce() -> R>(f: F) -> Result<R, String> {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(|c| c.get()) {
+                hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.with(|c| c.replace(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(was_catching));
+    return res.map_err(panic_message);
+}
+
+// the message a compile error panicked with
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    return String::from("The program could not be compiled");
+}
+
+// the blocks of a program, printing a warning for the code that
+// was left out because it is never reached
+fn to_blocks(parsed: Vec<DBCommand>, strict: bool) -> Vec<Block> {
+    let (blocks, warnings) = ir::to_blocks(parsed, strict);
+    for warning in warnings.iter() {
+        eprintln!("warning: {}", warning);
+    }
+    return blocks;
+}
+
+// the forms a program goes through while compiling, in order
+pub const STAGES: [&str; 6] = ["ast", "blocks", "loops", "ir", "quads", "bf"];
+
+// compiles a program and returns a dump of each of the given
+// stages, in the order they happen. With more than one stage, each
+// dump starts with a header naming it.
+pub fn emit_stages(prgm: String, semcom: bool, ircom: bool, opts: bool,
+                   options: &ir::Options, stages: &[&str]) -> String
 {
     let parsed = parser::parse_bytes(&prgm.into_bytes());
+    return emit_parsed_stages(parsed, semcom, ircom, opts, options, stages);
+}
+
+fn emit_parsed_stages(parsed: Vec<DBCommand>, semcom: bool, ircom: bool,
+                      opts: bool, options: &ir::Options, stages: &[&str])
+                      -> String
+{
+    let wants = |stage: &str| stages.contains(&stage);
+    let mut dumps = Vec::new();
 
-    let mut blocks = ir::to_blocks(parsed);
+    if wants("ast") {
+        dumps.push(("ast", parsed.iter().map(|c| format!("{}\n", c)).collect()));
+    }
+
+    // the AST alone doesn't need the code that is never reached to
+    // be left out, or the warnings about it
+    if wants("ast") && stages.len() == 1 {
+        return dumps.pop().unwrap().1;
+    }
+
+    let mut blocks = to_blocks(parsed, options.strict);
+    if wants("blocks") {
+        dumps.push(("blocks", ir::dump_blocks(&blocks)));
+    }
 
     ir::elim_gotos(&mut blocks);
+    if wants("loops") {
+        dumps.push(("loops", ir::dump_blocks(&blocks)));
+    }
         
-    let quads = ir::to_ir(blocks, opts);
+    let quads = ir::to_ir(blocks, opts, options);
+    if wants("ir") {
+        dumps.push(("ir", quads.iter().map(|q| format!("{:?}\n", q)).collect()));
+    }
     
     let v = codegen::resolve(quads, ircom, semcom);
-    let s = codegen::create_string(v);
-    return s;
-     
+    if wants("quads") {
+        dumps.push(("quads", v.iter().map(|q| format!("{:?}\n", q)).collect()));
+    }
+
+    if wants("bf") {
+        dumps.push(("bf", codegen::create_string(v)));
+    }
+
+    if dumps.len() == 1 {
+        return dumps.pop().unwrap().1;
+    }
+
+    return dumps.iter()
+        .map(|&(stage, ref text)| format!("=== {} ===\n{}", stage,
+                                          text.trim_end_matches('\n')))
+        .collect::<Vec<String>>()
+        .join("\n");
 }
 
 
 
-pub fn to_graphviz(prgm: String) -> String {
+// the version of the --json output, described in JSON.md. It
+// changes whenever a field is removed or changes meaning.
+pub const JSON_VERSION: u32 = 2;
+
+// the parsed program, its blocks after GOTO elimination and where
+// its variables are kept, as JSON
+pub fn to_json(prgm: String, opts: bool, options: &ir::Options) -> String {
     let parsed = parser::parse_bytes(&prgm.into_bytes());
-    let mut blocks = ir::to_blocks(parsed);
+    let program = serde_json::to_value(&parsed).unwrap();
+
+    let mut blocks = to_blocks(parsed, options.strict);
+    ir::elim_gotos(&mut blocks);
+    let block_json = serde_json::to_value(&blocks).unwrap();
+    let layout = ir::to_layout(blocks, opts, options);
+
+    let doc = json!({
+        "version": JSON_VERSION,
+        "program": program,
+        "blocks": block_json,
+        "layout": layout
+    });
+    return serde_json::to_string_pretty(&doc).unwrap();
+}
+
+// the control flow of a program, or with dominators, its
+// dominator tree
+pub fn to_graphviz(prgm: String, dominators: bool) -> String {
+    let parsed = parser::parse_bytes(&prgm.into_bytes());
+    let mut blocks = to_blocks(parsed, false);
     ir::elim_gotos(&mut blocks);
     
+    if dominators {
+        return ir::to_dominator_graphviz(&blocks);
+    }
     return ir::to_graphviz(&blocks);
 }
+
+// the program with its lines numbered start, start + step, ... and
+// its jumps changed to match
+pub fn renumber(prgm: String, start: u32, step: u32) -> String {
+    let parsed = parser::parse_bytes(&prgm.into_bytes());
+    let renumbered = ir::renumber(parsed, start, step);
+    return parser::print::program_to_string(&renumbered);
+}
+
+// the program in the canonical form of parser::print, or why it
+// could not be parsed
+pub fn format_program(prgm: String) -> Result<String, String> {
+    let parsed = parser::try_parse_bytes(&prgm.into_bytes())
+        .map_err(|e| e.to_string())?;
+    return Ok(parser::print::program_to_string(&parsed));
+}
+
+// the likely mistakes in a program, in line order
+pub fn check(prgm: String) -> Vec<ir::Warning> {
+    let parsed = parser::parse_bytes(&prgm.into_bytes());
+    return ir::lint(parsed);
+}
@@ -22,6 +22,10 @@
 mod test {
     use compile;
     use interp::BFEnv;
+    use ir;
+    use ir::Options;
+    use parser;
+    use serde_json;
 
     #[test]
     fn simple_print_test() {
@@ -51,6 +55,42 @@ mod test {
         assert_eq!(result, "25");
     }
 
+    #[test]
+    fn divide_test() {
+        let program = r#"
+10 PRINT 7 / 1, " ", 255 / 1, " ", 100 / 7, " ", 6 / 3, " ", 0 / 1
+20 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "7 255 14 2 0");
+    }
+
+    #[test]
+    fn def_test() {
+        let program = r#"
+10 DEF FNA(X) = X * 2
+20 DEF FNB(X) = FNA(X + 1) + Y
+30 LET X = 100 : LET Y = 10
+40 PRINT FNA(3), " ", FNB(2), " ", FNA(1 + 2), " ", X
+50 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "6 16 6 100");
+    }
+
+    #[test]
+    #[should_panic(expected = "Function FNA calls itself")]
+    fn def_recursion_test() {
+        let program = "10 DEF FNA(X) = FNA(X) + 1\n20 PRINT FNA(1)\n30 END\n";
+        compile::compile(String::from(program), false, false, true);
+    }
+
     #[test]
     fn simple_loop_test() {
         let program = r#"
@@ -281,5 +321,978 @@ mod test {
         assert_eq!(result, " in sub Again! in sub Done."); 
         
     }
-    
+
+    #[test]
+    fn on_goto_test() {
+        let program = r#"
+10  FOR I = 0 TO 5
+20  ON I GOTO 100, 200, 300
+30  PRINT "none "
+40  GOTO 400
+100 PRINT "one "
+110 GOTO 400
+200 PRINT "two "
+210 GOTO 400
+300 PRINT "three "
+400 NEXT I
+410 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "none one two three none ");
+    }
+
+    #[test]
+    fn on_goto_state_machine_test() {
+        let program = r#"
+10  LET S = 1
+20  ON S GOTO 100, 200, 300
+100 PRINT "a"
+110 LET S = 2
+120 GOTO 20
+200 PRINT "b"
+210 LET S = 3
+220 GOTO 20
+300 PRINT "c"
+310 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "abc");
+    }
+
+    #[test]
+    fn on_goto_later_exit_test() {
+        // the loop through line 20 can leave by falling out of the ON
+        // GOTO or by going to line 300. Neither is its first exit.
+        let program = r#"
+10  LET S = 1
+20  LET N = N + 1
+30  ON S GOTO 100, 200, 300
+40  PRINT "bad state ", N
+50  END
+100 LET S = 2
+110 GOTO 20
+200 LET S = 4
+210 GOTO 20
+300 PRINT "c"
+310 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "bad state 3");
+
+        let bf = compile::compile(program.replace("LET S = 4", "LET S = 3"),
+                                  false, false, true);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "c");
+
+        // the exits are in block order, so the program always
+        // compiles the same way
+        let (mut blocks, _) = ir::to_blocks(parser::parse_bytes(program.as_bytes()), false);
+        ir::elim_gotos(&mut blocks);
+        for b in blocks.iter().filter(|b| b.is_loop) {
+            let mut sorted = b.loop_exits.clone();
+            sorted.sort();
+            assert_eq!(b.loop_exits, sorted);
+        }
+    }
+
+    #[test]
+    fn on_gosub_test() {
+        let program = r#"
+10  FOR I = 0 TO 4
+20  ON I GOSUB 100, 200
+30  PRINT I, " "
+40  NEXT I
+50  END
+
+100 PRINT "a"
+110 RETURN
+200 PRINT "b"
+210 RETURN
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "0 a1 b2 3 ");
+    }
+
+    #[test]
+    fn single_line_if_test() {
+        let program = r#"
+10 FOR I = 0 TO 5
+20 IF I > 2 THEN PRINT "big "
+30 PRINT I
+40 NEXT I
+50 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "012big 3big 4");
+    }
+
+    #[test]
+    fn if_else_test() {
+        let program = r#"
+10 FOR I = 0 TO 5
+20 IF I = 2 THEN LET A = 1 ELSE LET A = 0
+30 PRINT A
+40 NEXT I
+50 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "00100");
+    }
+
+    #[test]
+    fn nested_if_test() {
+        let program = r#"
+10 LET X = 0
+20 LET X = X + 1
+30 IF X < 5 THEN 20
+40 IF X = 5 THEN IF X > 3 THEN PRINT "a" ELSE PRINT "b"
+50 IF X = 4 THEN PRINT "c" ELSE 70
+60 PRINT "d"
+70 PRINT X
+80 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "a5");
+    }
+
+    #[test]
+    fn multiple_statements_test() {
+        let program = r#"
+10 LET A = 1 : LET B = 2 : PRINT A + B
+20 FOR I = 0 TO 3 : PRINT I : NEXT I
+30 IF A = 1 THEN PRINT "x" : PRINT "y" ELSE PRINT "z" : PRINT "w"
+40 IF B = 1 THEN PRINT "x" : PRINT "y" ELSE PRINT "z" : PRINT "w"
+50 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "3012xyzw");
+    }
+
+    #[test]
+    fn goto_multiple_statements_test() {
+        let program = r#"
+10 LET X = 0
+20 LET X = X + 1 : PRINT X
+30 IF X < 3 THEN 20
+40 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "123");
+    }
+
+    #[test]
+    fn while_loop_test() {
+        let program = r#"
+10 LET X = 0
+20 WHILE X < 5
+30 PRINT X
+40 LET X = X + 1
+50 WEND
+60 WHILE X > 10
+70 PRINT "never"
+80 WEND
+90 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "01234");
+    }
+
+    #[test]
+    fn nested_while_loop_test() {
+        let program = r#"
+10 LET I = 0
+20 WHILE I < 3
+30 LET J = 0
+40 WHILE J < I
+50 PRINT J
+60 LET J = J + 1
+70 WEND
+80 PRINT "."
+90 LET I = I + 1
+100 WEND
+110 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, ".0.01.");
+    }
+
+    #[test]
+    fn do_loop_test() {
+        let program = r#"
+10 LET X = 5
+20 DO
+30 LET X = X - 1
+40 PRINT X
+50 LOOP UNTIL X = 2
+60 DO
+70 PRINT "once"
+80 LOOP WHILE X > 10
+90 DO : IF X = 3 THEN PRINT "t" ELSE PRINT "f"
+100 LET X = X + 1 : LOOP UNTIL X > 3
+110 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "432onceft");
+    }
+
+    #[test]
+    fn string_test() {
+        let program = r#"
+10 LET A$ = "HELLO"
+20 LET B$ = A$ + ", " + "WORLD"
+30 PRINT B$
+40 LET A$ = A$ + "!"
+50 PRINT "|", A$, "|"
+60 LET E$ = ""
+70 PRINT "[", E$, "]"
+80 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "HELLO, WORLD|HELLO!|[]");
+    }
+
+    #[test]
+    fn string_compare_test() {
+        let program = r#"
+10 LET A$ = "HELLO"
+20 IF A$ = "HELLO" THEN PRINT "eq" ELSE PRINT "ne"
+30 IF A$ < "HELP" THEN PRINT "lt" ELSE PRINT "ge"
+40 IF "AB" <= "A" THEN PRINT "le" ELSE PRINT "gt"
+50 IF A$ + "X" != A$ THEN PRINT "ne"
+60 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "eqltgtne");
+    }
+
+    #[test]
+    fn string_data_test() {
+        let program = r#"
+10 READ A$, X, B$
+20 PRINT B$ + A$, X
+30 DATA "foo", 7
+40 DATA "bar baz"
+50 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "bar bazfoo7");
+    }
+
+    #[test]
+    fn substring_test() {
+        let program = r#"
+10 LET A$ = "HELLO WORLD"
+20 PRINT LEN(A$), "|", LEFT$(A$, 5), "|", RIGHT$(A$, 5), "|"
+30 PRINT MID$(A$, 3, 4), "|", MID$(A$, 7), "|"
+40 PRINT RIGHT$(A$, 50), "|", MID$(A$, 20, 2), "|"
+50 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "11|HELLO|WORLD|LLO |WORLD|HELLO WORLD||");
+    }
+
+    #[test]
+    fn chr_asc_test() {
+        let program = r#"
+10 FOR X = 65 TO 70
+20 PRINT CHR$(X)
+30 NEXT X
+40 LET B$ = CHR$(104) + CHR$(105)
+50 PRINT B$, ASC(B$), ASC("")
+60 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "ABCDEhi1040");
+    }
+
+    #[test]
+    fn str_val_test() {
+        let program = r#"
+10 LET A$ = STR$(1204) + "," + STR$(0)
+20 PRINT A$, "|", VAL(" 4 5x6"), "|", VAL("abc"), "|"
+30 PRINT VAL(STR$(99)) + 1
+40 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "1204,0|45|0|100");
+    }
+
+    #[test]
+    fn fixed_point_test() {
+        let program = r#"
+10 LET A = 7
+20 LET B = 2
+30 PRINT A / B, " ", 2 / 3, " ", 1.25 * 4, " ", 0.1 + 0.25
+40 DIM C(3)
+50 LET C(1.4) = 3.5
+60 READ X
+70 PRINT " ", C(1), " ", X, " ", STR$(10 / 4), " ", VAL("3.14159") + 1
+80 DATA 2.5
+90 END
+"#;
+
+        let options = Options { decimals: 2, ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "3.50 0.67 5.00 0.35 3.50 2.50 2.50 4.14");
+    }
+
+    #[test]
+    fn truncated_decimal_test() {
+        let program = r#"
+10 READ X
+20 PRINT X + 0.5, " ", 7 / 2
+30 DATA 2.5
+40 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "2 3");
+    }
+
+    #[test]
+    fn mat_test() {
+        let program = r#"
+10 DIM A(2,3)
+20 DIM B(2,3)
+30 DIM C(2,3)
+40 DIM D(3,2)
+50 DIM E(2,2)
+60 DIM V(1)
+70 MAT READ A, D
+80 MAT B = CON : MAT C = A + B
+90 MAT PRINT C
+100 MAT E = A * D
+110 MAT PRINT E
+120 MAT E = IDN : MAT V = CON
+130 MAT PRINT E, V
+140 MAT B = ZER : MAT C = A - B : MAT PRINT C
+150 DATA 1, 2, 3, 4, 5, 6
+160 DATA 1, 0, 0, 1, 2, 2
+170 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "2 3 4\n5 6 7\n7 8\n16 17\n1 0\n0 1\n1\n\
+                            1 2 3\n4 5 6\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn mat_dimension_test() {
+        let program = r#"
+10 DIM A(2,3)
+20 DIM B(3,2)
+30 MAT A = B
+40 END
+"#;
+
+        compile::compile(String::from(program), false, false, true);
+    }
+
+    #[test]
+    fn default_storage_test() {
+        let program = r#"
+10 PRINT X, " "
+20 LET A(10) = 5
+30 LET B(3, 4) = A(10) + A(0)
+40 GOSUB 100
+50 PRINT A(10), " ", B(3, 4), " ", C(2), " ", Y + 1
+60 END
+100 DIM C(3)
+110 LET C(2) = 7
+120 RETURN
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "0 5 5 7 1");
+    }
+
+    #[test]
+    fn mat_default_storage_test() {
+        let program = r#"
+10 LET V(10) = 3
+20 MAT W = V : MAT M = CON
+30 PRINT W(10), " ", W(0), " ", M(10, 10), " ", M(0, 10)
+40 END
+"#;
+
+        // MAT statements work on arrays without a DIM too, which
+        // have 11 elements in each dimension
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "3 0 1 1");
+    }
+
+    #[test]
+    fn bounds_check_test() {
+        let program = r#"
+10 DIM A(3, 2)
+20 FOR I = 0 TO 5
+30 LET A(I, 1) = I
+40 PRINT A(I, 1), " "
+50 NEXT I
+60 PRINT "not reached"
+70 END
+"#;
+
+        let options = Options { check_bounds: true, ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "0 1 2 SUBSCRIPT OUT OF RANGE IN LINE 30\n");
+    }
+
+    #[test]
+    fn bounds_check_read_test() {
+        let program = r#"
+10 LET X = 11
+20 WHILE X > 0
+30 PRINT B(X - 1) + 1, " ", B(X), "no"
+40 LET X = X - 1
+50 WEND
+60 END
+"#;
+
+        let options = Options { check_bounds: true, ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "1 SUBSCRIPT OUT OF RANGE IN LINE 30\n");
+    }
+
+    #[test]
+    fn end_in_loop_test() {
+        let program = r#"
+10 FOR I = 0 TO 10
+20 LET J = 0
+30 WHILE J < 10
+40 PRINT I, J, " "
+50 IF I = 2 THEN IF J = 1 THEN END
+60 LET J = J + 1
+70 WEND
+80 NEXT I
+90 PRINT "not reached"
+100 END
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "00 01 02 03 04 05 06 07 08 09 \
+                            10 11 12 13 14 15 16 17 18 19 20 21 ");
+    }
+
+    #[test]
+    fn stop_in_subroutine_test() {
+        let program = r#"
+10 LET X = 1
+20 GOSUB 100
+30 PRINT "back "
+40 LET X = X + 1
+50 GOTO 20
+100 PRINT X, " "
+110 IF X = 3 THEN STOP
+120 RETURN
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "1 back 2 back 3 ");
+    }
+
+    #[test]
+    #[should_panic(expected = "RETURN on line 120 is inside the WHILE loop started on line 100")]
+    fn return_in_while_test() {
+        let program = r#"
+10  GOSUB 100
+20  PRINT "back"
+30  END
+100 WHILE 1 = 1
+110 PRINT "in"
+120 RETURN
+130 WEND
+"#;
+        compile::compile(String::from(program), false, false, true);
+    }
+
+    #[test]
+    fn gosub_in_while_test() {
+        // a subroutine called from inside a loop can still RETURN
+        let program = r#"
+10  WHILE I < 3
+20  GOSUB 100
+30  WEND
+40  END
+100 LET I = I + 1
+110 PRINT I
+120 RETURN
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "123");
+    }
+
+    #[test]
+    fn no_end_test() {
+        let program = r#"
+10 FOR I = 0 TO 3
+20 PRINT I
+30 NEXT I
+"#;
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "012");
+    }
+
+    #[test]
+    fn lenient_lexing_test() {
+        // lower case keywords, names with digits and underscores,
+        // tabs, CRLF line endings, blank lines and no final newline
+        let program = "\r\n10 let total_2 = 0\r\n\r\n\
+                       20 For i = 0 To 3\r\n\
+                       30 \tLET Total_2 = TOTAL_2 + I\r\n\
+                       40 next I\r\n  \r\n\
+                       50 Let a$ = \"x\ty\" : print total_2, \" \",\tlen(A$)\r\n\
+                       60 if total_2 > 2 then print \"!\" else print \"?\"";
+
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "3 3!");
+    }
+
+    #[test]
+    fn list_last_line_test() {
+        // statements that end in a list can be the last line, with
+        // or without a newline after them
+        for last in &["DIM Q(3)", "READ A, B", "ON A GOTO 10, 20",
+                      "ON A GOSUB 10, 20", "MAT READ A, B", "MAT PRINT A, B"] {
+            for ending in &["", "\n"] {
+                let program = format!("10 DATA 1, 2\n20 {}{}", last, ending);
+                let parsed = parser::try_parse_bytes(program.as_bytes()).unwrap();
+                assert_eq!(parsed.len(), 2);
+            }
+        }
+
+        let bf = compile::compile(String::from("10 DATA 4, 2\n20 READ A, B\n30 PRINT A + B\n40 DIM Q(3)"),
+                                  false, false, true);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "6");
+    }
+
+    #[test]
+    fn classic_print_test() {
+        let program = r#"
+10 LET A$ = "hey"
+20 PRINT 1; 22, "x"; A$, 345
+30 PRINT
+40 FOR I = 0 TO 3
+50 PRINT I;
+60 NEXT I
+70 PRINT CHR$(66), "0123456789abcdefg", 5;
+80 PRINT "!"
+90 END
+"#;
+
+        let options = Options { classic_print: true, ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "122            xhey           345\n\
+                            \n\
+                            012B           0123456789abcdefg             5!\n");
+
+        // separators just join items by default
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "122xhey345012B0123456789abcdefg5!");
+    }
+
+    #[test]
+    fn print_using_test() {
+        let program = r##"
+10 LET X = 2 / 3
+20 PRINT USING "[##.#] "; X, 42, 1234
+30 PRINT USING "000-##.000|"; 7, X
+40 PRINT "\n"
+50 PRINT "ab"; TAB(5); "c"; SPC(2); "d"; TAB(1); "e"
+60 END
+"##;
+
+        let options = Options { decimals: 2, ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "[ 0.7] [42.0] [1234.0] \
+                            007- 0.670|\n\
+                            ab   c  de");
+    }
+
+    #[test]
+    fn emit_stages_test() {
+        let program = r#"
+10 READ X
+20 PRINT X
+30 DATA 7
+"#;
+
+        let options = Options::default();
+        let dump = compile::emit_stages(String::from(program), false, false,
+                                        true, &options, &["bf", "ast"]);
+        let bf = compile::compile(String::from(program), false, false, true);
+        assert!(dump.starts_with("=== ast ===\n\
+                                  10 READ { varnames: [VAR(\"X\")] }\n"));
+        assert!(dump.ends_with(&format!("=== bf ===\n{}", bf)));
+
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "7");
+    }
+
+    #[test]
+    fn graphviz_test() {
+        let program = r#"
+10 LET I = 0
+20 LET I = I + 1
+30 FOR J = 0 TO 2
+40 PRINT J
+50 NEXT J
+60 IF I < 2 THEN 20
+70 END
+"#;
+
+        // the GOTO loop is a block without statements, drawn as the
+        // entry of a cluster around its body
+        let gv = compile::to_graphviz(String::from(program), false);
+        assert!(gv.contains("6 [label=\"6: loop\", shape=ellipse];"));
+        assert!(gv.contains("subgraph cluster_6 {"));
+        assert!(gv.contains("[style=dotted, label=\"next\"]"));
+        assert!(gv.contains("40 PRINT"));
+
+        let dom = compile::to_graphviz(String::from(program), true);
+        assert!(dom.contains("6 -> 1;"));
+        assert!(dom.contains("0 -> 6;"));
+    }
+
+    #[test]
+    fn json_test() {
+        let program = r#"
+10 DIM A(3)
+20 LET A$ = "x"
+30 READ X, A(1)
+40 DATA 4, 5
+50 IF X = 4 THEN PRINT A$
+"#;
+
+        let json = compile::to_json(String::from(program), true,
+                                    &Options::default());
+        let doc: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(doc["version"], compile::JSON_VERSION);
+        assert_eq!(doc["program"][0], json!({
+            "ln": 10,
+            "cmd": { "DIM": { "arrays": [{ "varname": "A",
+                                           "dims": [{ "N": 3 }] }] } },
+            "data": []
+        }));
+        assert_eq!(doc["program"][4]["cmd"]["IF"]["then_stmts"][0],
+                   json!({ "PRINT": { "seq": [{ "E": { "V": "A$" } }],
+                                      "seps": [] } }));
+
+        // the READ gets its DATA once the program is split into blocks
+        let read = &doc["blocks"][0]["cmds"][2];
+        assert_eq!(read["data"], json!([{ "N": 4.0 }, { "N": 5.0 }]));
+        assert_eq!(doc["blocks"][0]["out_blocks"], json!([1, 2]));
+
+        assert_eq!(doc["layout"]["variables"]["X"], 0);
+        assert_eq!(doc["layout"]["arrays"]["A"]["dims"], json!([3]));
+        assert!(doc["layout"]["strings"]["A$"].is_u64());
+    }
+
+    #[test]
+    fn divide_by_zero_check_test() {
+        let program = r#"
+10 FOR I = 0 TO 4
+20 LET X = 2 - I
+30 PRINT 6 / X, " "
+40 NEXT I
+50 PRINT "not reached"
+60 END
+"#;
+
+        let options = Options { check_arithmetic: true, ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "3 6 DIVISION BY ZERO IN LINE 30\n");
+    }
+
+    #[test]
+    fn first_error_only_test() {
+        // once a statement raises an error, the rest of it doesn't
+        // print more
+        let options = Options { check_bounds: true, ..Options::default() };
+        let bf = compile::compile_with(String::from("10 DIM Q(3)\n20 PRINT Q(5), 42, Q(7)\n"),
+                                       false, false, true, &options);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "SUBSCRIPT OUT OF RANGE IN LINE 20\n");
+
+        let options = Options { check_arithmetic: true, ..Options::default() };
+        let bf = compile::compile_with(String::from("10 LET A = 0\n20 PRINT 5 / A, 3 - 4\n"),
+                                       false, false, true, &options);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "DIVISION BY ZERO IN LINE 20\n");
+    }
+
+    #[test]
+    fn underflow_check_test() {
+        let program = r#"
+10 LET X = 3
+20 WHILE X < 10
+30 LET X = X - 1
+40 PRINT X, " "
+50 WEND
+60 END
+"#;
+
+        let options = Options { check_arithmetic: true, decimals: 1,
+                                ..Options::default() };
+        let bf = compile::compile_with(String::from(program), false, false,
+                                       true, &options);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "2.0 1.0 0.0 NEGATIVE RESULT IN LINE 30\n");
+    }
+
+    #[test]
+    fn renum_test() {
+        let program = r#"
+5 REM counts to 3
+7 LET X = 0
+8 GOSUB 100 : IF X < 3 THEN 8 ELSE GOTO 12
+12 ON X - 2 GOTO 13, 8
+13 PRINT "done\n" : END
+100 LET X = X + 1 : PRINT X; : RETURN
+"#;
+
+        let renumbered = compile::renumber(String::from(program), 100, 20);
+        assert_eq!(renumbered, r#"100 REM counts to 3
+120 LET X = 0
+140 GOSUB 200 : IF X < 3 THEN 140 ELSE 160
+160 ON X - 2 GOTO 180, 140
+180 PRINT "done\n" : END
+200 LET X = X + 1 : PRINT X; : RETURN
+"#);
+
+        let bf = compile::compile(renumbered, false, false, true);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "123done\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Jump to line 30, which does not exist")]
+    fn renum_missing_line_test() {
+        compile::renumber(String::from("10 GOTO 30\n20 END\n"), 10, 10);
+    }
+
+    #[test]
+    fn fmt_test() {
+        // formatting keeps what a program does, even when it drops
+        // parentheses
+        let program = "10 let x=(1+2)*(3-(4-1))+((5))\n20 print x\n";
+        let formatted = compile::format_program(String::from(program)).unwrap();
+        assert_eq!(formatted, "10 LET X = (1 + 2) * ((3 - (4 - 1)) + 5)\n20 PRINT X\n");
+
+        for prgm in &[program, include_str!("../../examples/fib.db"),
+                      include_str!("../../examples/game_of_life.db")] {
+            let formatted = compile::format_program(String::from(*prgm)).unwrap();
+            assert_eq!(compile::compile(formatted, false, false, true),
+                       compile::compile(String::from(*prgm), false, false, true));
+        }
+
+        let bf = compile::compile(formatted, false, false, true);
+        let mut interp = BFEnv::new();
+        assert_eq!(interp.execute(bf), "15");
+
+        // a program that can't be parsed is an error, not a panic
+        assert_eq!(compile::format_program(String::new()),
+                   Err(String::from("The program has no lines")));
+        assert_eq!(compile::format_program(String::from("10 PRONT\n")),
+                   Err(String::from("Could not parse line: 10 PRONT")));
+        assert_eq!(compile::format_program(String::from("10 PRINT \"a\\\"\n")),
+                   Err(String::from("Could not parse line: 10 PRINT \"a\\\"")));
+    }
+
+    #[test]
+    fn check_test() {
+        let program = r#"
+10 DEF FNA(X) = X * 2
+20 DEF FNB(X) = X + Y
+30 LET A = 1 : LET U = 2
+40 FOR I = 1 TO 3
+50 IF I = 2 THEN LET I = 3
+60 PRINT FNB(I) + Z
+70 NEXT I
+75 DO : LET W = 1 : LOOP UNTIL A = 1
+76 PRINT W
+80 IF A = 1 THEN 100
+90 LET Z = 5
+100 PRINT Z
+110 END
+120 PRINT "dead"
+130 PRINT "dead too"
+"#;
+
+        let warnings: Vec<String> = compile::check(String::from(program))
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        assert_eq!(warnings, vec![
+            "line 10: function FNA is never called",
+            "line 30: U is never read",
+            "line 50: FOR variable I is changed inside the loop started on line 40",
+            "line 60: Y can be read before it is given a value",
+            "line 60: Z can be read before it is given a value",
+            "line 120: lines 120 to 130 are never reached"
+        ]);
+
+        // a subroutine without a RETURN is gone to like a GOTO, so
+        // the rest of the warnings are still found
+        let warnings: Vec<String> = compile::check(String::from("10 GOSUB 30\n20 END\n30 PRINT Z\n"))
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        assert_eq!(warnings, vec![
+            "line 10: GOSUB 30 goes to a subroutine without a RETURN",
+            "line 20: this line is never reached",
+            "line 30: Z can be read before it is given a value"
+        ]);
+
+        // a function only called by another one is still called
+        let program = "10 DEF FNA(X) = X * 2\n20 DEF FNB(X) = FNA(X) + 1\n30 PRINT FNB(2)\n";
+        assert!(compile::check(String::from(program)).is_empty());
+
+        for prgm in &[include_str!("../../examples/fib.db"),
+                      include_str!("../../examples/game_of_life.db")] {
+            assert!(compile::check(String::from(*prgm)).is_empty());
+        }
+    }
+
+    #[test]
+    fn dead_code_test() {
+        let program = r#"
+10 FOR I = 1 TO 3
+20 PRINT I
+30 GOTO 60
+40 NEXT I
+50 PRINT "skipped"
+60 GOSUB 90
+70 END
+80 PRINT "never"
+90 PRINT " done"
+100 RETURN
+110 PRINT "never either"
+120 RETURN
+"#;
+
+        // lines that are never reached are left out
+        let bf = compile::compile(String::from(program), false, false, true);
+        let mut interp = BFEnv::new();
+        let result = interp.execute(bf);
+        assert_eq!(result, "1 done");
+
+        let (_, warnings) = ir::to_blocks(parser::parse_bytes(program.as_bytes()), false);
+        let warnings: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(warnings, vec![
+            "line 40: this line is never reached, so it was left out",
+            "line 80: this line is never reached, so it was left out",
+            "line 110: lines 110 to 120 are never reached, so they were left out"
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dead_code_strict_test() {
+        let program = r#"
+10 PRINT "a"
+20 GOTO 40
+30 PRINT "b"
+40 END
+"#;
+
+        let options = Options { strict: true, ..Options::default() };
+        compile::compile_with(String::from(program), false, false, true, &options);
+    }
 }
@@ -19,6 +19,8 @@
 // < end copyright > 
  
 use std::char;
+use std::io;
+use std::io::prelude::*;
 use std::mem::{drop};
 
 mod full_tests;
@@ -39,7 +41,9 @@ impl BFEnv {
         return to_r;
     }
 
-    fn execute_single(&mut self, instruction: char) -> Option<String> {
+    fn execute_single<R: Read, W: Write>(&mut self, instruction: char,
+                                         input: &mut R, output: &mut W)
+                                         -> io::Result<()> {
         match instruction {
             '+' => {
                 self.data[self.pt] += 1
@@ -50,7 +54,18 @@ impl BFEnv {
             },
 
             '.' => {
-                return Some(char::from_u32(self.data[self.pt]).unwrap().to_string());
+                let chr = char::from_u32(self.data[self.pt]).unwrap();
+                let mut buf = [0; 4];
+                output.write_all(chr.encode_utf8(&mut buf).as_bytes())?;
+            },
+
+            ',' => {
+                // the end of the input reads as zero
+                let mut byte = [0; 1];
+                self.data[self.pt] = match input.read(&mut byte)? {
+                    0 => 0,
+                    _ => byte[0] as u32
+                };
             },
 
             '>' => {
@@ -72,7 +87,7 @@ impl BFEnv {
             _ => { }
         };
 
-        return None;
+        return Ok(());
     }
 
     fn find_matching_close(open: usize, prgm: &[char]) -> usize {
@@ -113,8 +128,21 @@ impl BFEnv {
                close);
     }
 
+    // runs source without any input, and returns everything it
+    // printed
+    #[cfg(test)]
     pub fn execute(&mut self, source: String) -> String {
-        let mut result = String::new();
+        let mut result = Vec::new();
+        self.run(source, &mut io::empty(), &mut result)
+            .expect("writing to memory can't fail");
+        return String::from_utf8(result).unwrap();
+    }
+
+    // runs source, reading the input for , from input and writing
+    // each character to output as soon as it is printed
+    pub fn run<R: Read, W: Write>(&mut self, source: String,
+                                  input: &mut R, output: &mut W)
+                                  -> io::Result<()> {
         let program = {
             let mut p = Vec::new();
             p.extend(source.chars());
@@ -147,18 +175,13 @@ impl BFEnv {
                 },
 
                 _ => {
-                    let res = self.execute_single(program[pc]);
-                    
-                    if let Some(s) = res {
-                        result.push_str(s.as_str());
-                    }
-                    
+                    self.execute_single(program[pc], input, output)?;
                     pc += 1;
                 }
             }
         }
 
-        return result;
+        return output.flush();
     }
 
     #[cfg(test)]
@@ -203,4 +226,16 @@ mod test {
         assert_eq!(interp.data[2], 0);
 
     }
+
+    #[test]
+    fn input_test() {
+        let mut interp = BFEnv::new();
+        let mut output = Vec::new();
+        // echoes the input with each character moved up by one,
+        // then reads zero at the end of it
+        interp.run(String::from(",[+.,]>,"), &mut &b"HAL"[..], &mut output)
+            .unwrap();
+        assert_eq!(output, b"IBM");
+        assert_eq!(interp.data[1], 0);
+    }
 }
@@ -19,22 +19,65 @@
 // < end copyright > 
 use ir::blockgen::{Block, SpecialOut};
 use ir::allocator::{Allocator};
-use std::collections::{HashMap};
-use codegen::{BFQuad};
-use parser::structs::{DBStmt, DBExpr, DBArrayDef, DBLetTarget};
+use ir::layout;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use codegen::{BFQuad, STRING_CAPACITY, STRING_SCRATCH};
+use parser::structs::{DBStmt, DBExpr, DBArrayDef, DBLetTarget, DBData,
+                      DBPrintSep};
 use parser::ast::{Expr, OpCode};
 use optimizer;
 
+// settings that change what the compiled program does
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // how many decimal places numbers keep. Zero means integers.
+    pub decimals: u32,
+    // stop with an error when an array index is out of range
+    pub check_bounds: bool,
+    // stop with an error on division by zero or a negative result
+    pub check_arithmetic: bool,
+    // PRINT like classic BASIC: a comma moves to the next print
+    // zone, and a newline is added unless the statement ends with
+    // a separator
+    pub classic_print: bool,
+    // stop with an error on code that is never reached, instead of
+    // leaving it out with a warning
+    pub strict: bool
+}
+
+// the width of a print zone, for classic PRINT
+const PRINT_ZONE: u32 = 15;
+
 pub struct BlockToIR {
     ir: Vec<BFQuad>,
     alloc: Allocator,
     def_map: HashMap<String, (String, DBExpr)>,
     array_t: HashMap<String, (Vec<usize>, u32)>,
     symbol_t: HashMap<String, u32>,
+    string_t: HashMap<String, u32>,
     blocks: Vec<Block>,
     loop_stack: Vec<(usize, u32, u32)>,
     const_opt: bool,
-    already_used_array: bool
+    options: Options,
+    // cleared when the program stops early, if it can
+    running: Option<u32>,
+    // the line being compiled, for error messages
+    line: u32,
+    // how many loop bodies and subroutines the code being emitted
+    // is inside of, since the innermost GOTO loop
+    nesting: u32,
+    // set when an END inside a loop or subroutine was emitted
+    // without the running flag
+    nested_end: bool,
+    // the output column, for classic PRINT and TAB
+    column: Option<u32>,
+    // the DEF functions whose bodies are being emitted
+    calls: Vec<String>,
+    // the FOR, WHILE and DO loops the code being emitted is inside
+    // of, with the lines they start on, since the innermost
+    // subroutine
+    open_loops: Vec<(&'static str, u32)>
 }
 
 
@@ -72,17 +115,25 @@ macro_rules! comment {
 }
 
 impl BlockToIR {
-    pub fn new(blocks: Vec<Block>, const_opt: bool) -> BlockToIR {
+    pub fn new(blocks: Vec<Block>, const_opt: bool, options: Options) -> BlockToIR {
         return BlockToIR {
             ir: Vec::new(),
             alloc: Allocator::new(),
             def_map: HashMap::new(),
             array_t: HashMap::new(),
             symbol_t: HashMap::new(),
+            string_t: HashMap::new(),
             blocks: blocks,
             loop_stack: Vec::new(),
             const_opt: const_opt,
-            already_used_array: false
+            options: options,
+            running: None,
+            line: 0,
+            nesting: 0,
+            nested_end: false,
+            column: None,
+            calls: Vec::new(),
+            open_loops: Vec::new()
         };
     }
 
@@ -99,14 +150,94 @@ impl BlockToIR {
             
             self.alloc.free_array(*pos, accum);
         }
+
+        for pos in self.string_t.values() {
+            self.alloc.free_array(*pos, STRING_CAPACITY + 1);
+        }
+
+        if let Some(running) = self.running {
+            self.alloc.free(running);
+        }
+
+        if let Some(column) = self.column {
+            self.alloc.free(column);
+        }
         
         self.alloc.assert_empty();
     }
 
     pub fn generate_ir(&mut self) {
+        let blocks = self.blocks.clone();
+        self.emit_program(false);
+
+        // an END inside a loop or subroutine has to stop the code
+        // around it too, which needs the running flag. That is only
+        // known once the program has been emitted, so it is emitted
+        // again with the flag.
+        if self.nested_end {
+            let mut again = BlockToIR::new(blocks, self.const_opt,
+                                           self.options.clone());
+            again.emit_program(true);
+            *self = again;
+        }
+    }
+
+    fn emit_program(&mut self, can_stop: bool) {
+        self.layout_storage();
+
+        if self.options.classic_print || layout::uses_tab(&self.blocks) {
+            self.column = Some(get_and_zero!(self));
+        }
+
+        if can_stop || self.options.check_bounds
+            || self.options.check_arithmetic {
+            let running = get_and_zero!(self);
+            self.ir.push(BFQuad::To(running));
+            self.ir.push(BFQuad::RawBF("+"));
+            self.running = Some(running);
+        }
+        
         self.block_to_ir(0);
     }
 
+    // gives every variable and array its cells before any code is
+    // emitted. The cells have never been used, so variables read
+    // before they are assigned and array elements start at zero.
+    fn layout_storage(&mut self) {
+        let storage = layout::find_storage(&self.blocks);
+        for var in storage.vars {
+            let pos = self.alloc.reserve();
+            self.symbol_t.insert(var, pos);
+        }
+
+        for (name, dims) in storage.arrays {
+            let total_size = dims.iter().product::<usize>() as u32;
+            let arr_pos = self.alloc.reserve_array(total_size);
+            self.array_t.insert(name, (dims, arr_pos));
+        }
+
+        self.def_map = storage.functions;
+    }
+
+    pub fn get_layout(&self) -> layout::Layout {
+        let arrays = self.array_t.iter()
+            .map(|(name, &(ref dims, start))| {
+                (name.clone(), layout::ArrayLayout { start: start,
+                                                     dims: dims.clone() })
+            })
+            .collect();
+
+        return layout::Layout {
+            variables: self.symbol_t.iter()
+                .map(|(name, &pos)| (name.clone(), pos))
+                .collect(),
+            arrays: arrays,
+            strings: self.string_t.iter()
+                .map(|(name, &pos)| (name.clone(), pos))
+                .collect()
+        };
+    }
+
     pub fn get_ir(mut self) -> Vec<BFQuad> {
         self.done();
         let x = self.ir;
@@ -131,8 +262,11 @@ impl BlockToIR {
             self.ir.push(BFQuad::RawBF("["));
             comment!(self, "start of loop body");
             let oblock = self.blocks[block].out_blocks[0];
+            let outer_nesting = mem::replace(&mut self.nesting, 0);
             self.block_to_ir(oblock);
+            self.nesting = outer_nesting;
             comment!(self, "end of loop body");
+            self.emit_halt_check(loop_var);
             self.ir.push(BFQuad::To(loop_var));
             self.ir.push(BFQuad::RawBF("]"));
             
@@ -148,10 +282,13 @@ impl BlockToIR {
                 let t1 = get_and_zero!(self);
                 let t2 = get_and_zero!(self);
                 let v = get_and_zero!(self);
-                
+
+                // the comparison uses up its operands, and cond_var
+                // is needed again for the exits after this one
+                let exit = self.emit_copy(cond_var);
                 self.ir.push(BFQuad::To(v));
                 self.ir.push(BFQuad::Constant(idx as u32 + 1));
-                self.ir.push(BFQuad::Equal(cond_var, v, cond, t1, t2));
+                self.ir.push(BFQuad::Equal(exit, v, cond, t1, t2));
                 
                 comment!(self, "if loop condition is true: taking this exit");
                 self.ir.push(BFQuad::If(cond));
@@ -163,6 +300,7 @@ impl BlockToIR {
                 self.alloc.free(t1);
                 self.alloc.free(t2);
                 self.alloc.free(v);
+                self.alloc.free(exit);
             }
             self.alloc.free(loop_var);
             self.alloc.free(cond_var);
@@ -196,51 +334,18 @@ impl BlockToIR {
             if should_be_end {
                 panic!("Command type should have ended a block but didn't!");
             }
+
+            self.line = dbcmd.ln;
+            let guard = self.open_guard(&dbcmd.cmd);
             
             match dbcmd.cmd {
-                DBStmt::DEF { ref funcname, ref varname, ref expr } => {
-                    self.def_map.insert(funcname.clone(),
-                                        (varname.clone(), expr.clone()));
+                DBStmt::DEF { .. } => {
+                    // functions are found before any code is emitted,
+                    // and their bodies are emitted where they are called
                 },
                 
-                DBStmt::DIM { ref arrays } => {
-                    
-                    for array in arrays {
-                        if self.already_used_array {
-                            panic!("Defintion of array {} must come before the
-                                    first use of any array",
-                                   array.varname);
-                        }
-                        
-                        // reserve space for the array
-                        let mut dimensions = Vec::new();
-                        let mut total_size: u32 = 1;
-                        
-                        for expr in array.dims.iter() {
-                            match *expr {
-                                Expr::O (_,_,_) |
-                                    Expr::A(_) |
-                                    Expr::V(_) |
-                                    Expr::E(_)
-                                    => {
-                                        panic!("DIM statement for array {} must give a fixed size",
-                                               array.varname);
-                                    }
-                                Expr::N(num) => {
-                                    if num < 1 {
-                                        panic!("Array {} must have all > 0 dimensions",
-                                               array.varname);
-                                    }
-                                    dimensions.push(num as usize);
-                                    total_size *= num as u32;
-                                }
-                            }
-                        }
-
-                        let arr_pos = self.alloc.reserve_array(total_size);
-                        self.array_t.insert(array.varname.clone(),
-                                            (dimensions, arr_pos));
-                    }
+                DBStmt::DIM { .. } => {
+                    // arrays are laid out before any code is emitted
                 },
                 
                 DBStmt::FOR { ref varname, ref from_expr, ref to_expr, .. } => {
@@ -290,11 +395,12 @@ impl BlockToIR {
                     
                     self.ir.push(BFQuad::RawBF("["));
                     let out_idx = self.blocks[block].out_blocks[1];
-                    self.block_to_ir(out_idx);
+                    self.emit_loop_body("FOR", dbcmd.ln, out_idx);
+                    self.line = dbcmd.ln;
                     
                     // increment the variable
                     self.ir.push(BFQuad::To(var_pos));
-                    self.ir.push(BFQuad::RawBF("+"));
+                    self.ir.push(BFQuad::Constant(self.scale()));
                     
                     // check the condition
                     {
@@ -319,6 +425,7 @@ impl BlockToIR {
                         self.alloc.free(t3);
                         self.alloc.free(expr_result_pos);
                         self.alloc.free(var_copy);
+                        self.emit_halt_check(cond_pos);
                         self.ir.push(BFQuad::To(cond_pos));       
                         self.alloc.free(cond_pos);
                     }
@@ -332,6 +439,96 @@ impl BlockToIR {
                 DBStmt::NEXT { .. } => {
                     should_be_end = true;
                 }
+
+                DBStmt::WHILE { ref expr1, ref op, ref expr2 } => {
+                    comment!(self, "Start of while loop");
+                    let loop_cond = get_and_zero!(self);
+                    let cond = self.emit_condition(expr1, op, expr2);
+                    self.ir.push(BFQuad::Move(cond, loop_cond));
+                    self.alloc.free(cond);
+
+                    self.ir.push(BFQuad::To(loop_cond));
+                    self.ir.push(BFQuad::RawBF("["));
+                    let out_idx = self.blocks[block].out_blocks[1];
+                    self.emit_loop_body("WHILE", dbcmd.ln, out_idx);
+                    self.line = dbcmd.ln;
+
+                    // check the condition again
+                    let cond = self.emit_condition(expr1, op, expr2);
+                    self.ir.push(BFQuad::Zero(loop_cond));
+                    self.ir.push(BFQuad::Move(cond, loop_cond));
+                    self.alloc.free(cond);
+                    self.emit_halt_check(loop_cond);
+
+                    self.ir.push(BFQuad::To(loop_cond));
+                    self.ir.push(BFQuad::RawBF("]"));
+                    self.alloc.free(loop_cond);
+                    comment!(self, "End of while loop");
+
+                    let out_idx = self.blocks[block].out_blocks[0];
+                    self.block_to_ir(out_idx);
+                    should_be_end = true;
+                },
+
+                DBStmt::DO => {
+                    comment!(self, "Start of do loop");
+                    // the body always runs at least once.
+                    let loop_cond = get_and_zero!(self);
+                    self.ir.push(BFQuad::To(loop_cond));
+                    self.ir.push(BFQuad::RawBF("+"));
+                    self.ir.push(BFQuad::RawBF("["));
+                    let out_idx = self.blocks[block].out_blocks[1];
+                    self.emit_loop_body("DO", dbcmd.ln, out_idx);
+
+                    // the condition lives on the matching LOOP statement
+                    let end_cmd = self.blocks.iter()
+                        .filter(|b| match b.special_out {
+                            SpecialOut::Next(idx) => idx == block,
+                            _ => false
+                        })
+                        .filter_map(|b| b.cmds.last())
+                        .next()
+                        .cloned()
+                        .expect("DO statement did not have a matching LOOP!");
+                    self.line = end_cmd.ln;
+
+                    if let DBStmt::LOOP { until, ref expr1, ref op, ref expr2 } = end_cmd.cmd {
+                        let cond = self.emit_condition(expr1, op, expr2);
+                        self.ir.push(BFQuad::Zero(loop_cond));
+                        if until {
+                            self.ir.push(BFQuad::Not(cond, loop_cond));
+                        } else {
+                            self.ir.push(BFQuad::Move(cond, loop_cond));
+                        }
+                        self.alloc.free(cond);
+                    } else {
+                        panic!("DO statement did not have a matching LOOP!");
+                    }
+                    self.emit_halt_check(loop_cond);
+
+                    self.ir.push(BFQuad::To(loop_cond));
+                    self.ir.push(BFQuad::RawBF("]"));
+                    self.alloc.free(loop_cond);
+                    comment!(self, "End of do loop");
+
+                    let out_idx = self.blocks[block].out_blocks[0];
+                    self.block_to_ir(out_idx);
+                    should_be_end = true;
+                },
+
+                DBStmt::WEND | DBStmt::LOOP { .. } => {
+                    should_be_end = true;
+                }
+
+                DBStmt::END | DBStmt::STOP => {
+                    // nothing is emitted after an END, but a loop or
+                    // subroutine around it would carry on
+                    if let Some(running) = self.running {
+                        self.ir.push(BFQuad::Zero(running));
+                    } else if self.nesting > 0 || !self.loop_stack.is_empty() {
+                        self.nested_end = true;
+                    }
+                },
                 
                 DBStmt::GOSUB { .. } => {
                     let out_idx0 = self.blocks[block].out_blocks[0];
@@ -340,12 +537,20 @@ impl BlockToIR {
                         _ => panic!("GOSUB did not have special out set!")
                     };
                     
-                    self.block_to_ir(out_idx0);
+                    self.emit_subroutine(|this| this.emit_nested(out_idx0));
                     self.block_to_ir(out_idx1);
                     should_be_end = true;
                 },
 
                 DBStmt::RETURN => {
+                    // a FOR, WHILE or DO loop is only left through
+                    // its end, so a RETURN can't leave it
+                    if let Some(&(kind, ln)) = self.open_loops.last() {
+                        panic!("RETURN on line {} is inside the {} loop started on line {}",
+                               dbcmd.ln, kind, ln);
+                    }
+
+
                     if self.blocks[block].out_blocks.len() != 1 {
                         panic!("Return statment should have exactly one
                                 out block!");
@@ -375,47 +580,40 @@ impl BlockToIR {
                 DBStmt::GOTO { .. } => {
                     let out_idx = self.blocks[block].out_blocks[0];
                     should_be_end = true;
-                    // it is possible that this GOTO is taking us out of a loop
-                    // check to see if this GOTO jumps back to the current loop header
-                    if !self.loop_stack.is_empty()
-                        && self.loop_stack.last().unwrap().0 == out_idx
-                    {
-                        // this GOTO is jumping back to the loop header!
-                        // we shouldn't do anything but stop.
-                    } else {
-                        // this is a forward GOTO
-                        self.block_to_ir(out_idx);
-                    }
+                    // it is possible that this GOTO is jumping back to
+                    // the loop header, or taking us out of a loop.
+                    self.emit_jump(out_idx);
+                },
+                
+                DBStmt::ONGOTO { ref expr, ref linenos } => {
+                    // the out blocks are the targets, in order,
+                    // followed by the next line.
+                    let targets = self.blocks[block].out_blocks.clone();
+                    let num_targets = linenos.len();
+                    self.emit_on_dispatch(expr, &targets[..num_targets],
+                                          Some(targets[num_targets]));
+                    should_be_end = true;
+                },
+
+                DBStmt::ONGOSUB { ref expr, ref linenos } => {
+                    let targets = self.blocks[block].out_blocks.clone();
+                    let return_idx = match self.blocks[block].special_out {
+                        SpecialOut::Return (pos) => pos,
+                        _ => panic!("ON GOSUB did not have special out set!")
+                    };
+
+                    self.nesting += 1;
+                    self.emit_subroutine(|this| {
+                        this.emit_on_dispatch(expr, &targets[..linenos.len()], None);
+                    });
+                    self.nesting -= 1;
+                    self.block_to_ir(return_idx);
+                    should_be_end = true;
                 },
                 
                 DBStmt::IF { ref expr1, ref op, ref expr2, .. } => {
                     comment!(self, "Start of if statement");
-                    
-                    let (loc1, expr1_code) = self.ir_for_expression(expr1);
-                    let (loc2, expr2_code) = self.ir_for_expression(expr2);
-                    
-                    self.ir.extend(expr1_code);
-                    self.ir.extend(expr2_code);
-                    
-                    let cond = get_and_zero!(self);
-                    let t1 = get_and_zero!(self);
-                    let t2 = get_and_zero!(self);
-                    
-                    let action = match op.as_ref() {
-                        "="  => BFQuad::Equal(loc1, loc2, cond, t1, t2),
-                        ">"  => BFQuad::Greater(loc1, loc2, cond, t1, t2),
-                        "<"  => BFQuad::Less(loc1, loc2, cond, t1, t2),
-                        "!=" => BFQuad::NotEqual(loc1, loc2, cond, t1, t2),
-                        ">=" => BFQuad::GreaterOrEqual(loc1, loc2, cond, t1, t2),
-                        "<=" => BFQuad::LessOrEqual(loc1, loc2, cond, t1, t2),
-                        _ => panic!("unsupported relop")
-                    };
-                    
-                    self.ir.push(action);
-                    self.alloc.free(t1);
-                    self.alloc.free(t2);
-                    self.alloc.free(loc1);
-                    self.alloc.free(loc2);
+                    let cond = self.emit_condition(expr1, op, expr2);
                     
                     let else_tmp = get_and_zero!(self);
                     
@@ -423,72 +621,17 @@ impl BlockToIR {
                     let out_idx1 = self.blocks[block].out_blocks[1];
                     self.ir.push(BFQuad::IfElse(cond, else_tmp));
                     
-                    let does_loop_exit: bool;
-                    if !self.loop_stack.is_empty() {
-                        let &(block_idx, loop_var, cond_var) =
-                            self.loop_stack.last().unwrap();
-
-                        let index0;
-                        let index1;
-                        {
-                            let loop_exits = &self.blocks[block_idx].loop_exits;
-                        
-                            index0 = loop_exits.iter()
-                                .position(|&e| e == out_idx0);
-                            index1 = loop_exits.iter()
-                                .position(|&e| e == out_idx1);
-                        }
-                        
-                        if index0.is_some() || index1.is_some() {
-                            does_loop_exit = true;
-                            // one of these if branches leaves the loop!
-                            match index0 {
-                                None => {},
-                                Some (_) => {
-                                    assert!(!index1.is_some());
-                                    mark_loop_done!(self, loop_var,
-                                                    cond_var, index0.unwrap());
-                                    comment!(self, "else");
-                                    self.ir.push(BFQuad::Else(cond, else_tmp));
-                                    self.block_to_ir(out_idx1);
-
-                                    comment!(self, "end if");
-                                    self.ir.push(BFQuad::EndElse(else_tmp));
-                                }
-                            };
-                            
-                            match index1 {
-                                None => {},
-                                Some (_) => {
-                                    assert!(!index0.is_some());
-                                    self.block_to_ir(out_idx1);
-                                    
-                                    comment!(self, "else");
-                                    self.ir.push(BFQuad::Else(cond, else_tmp));
-                                    mark_loop_done!(self, loop_var,
-                                                    cond_var, index1.unwrap());
-
-                                    comment!(self, "end if");
-                                    self.ir.push(BFQuad::EndElse(else_tmp));
-                                }
-                            };
-                        } else {
-                            does_loop_exit = false;
-                        }
-                    } else {
-                        does_loop_exit = false;
-                    }
-                    
-                    if !does_loop_exit {
-                        self.block_to_ir(out_idx0);
+                    // either branch may jump back to the loop
+                    // header or leave the loop, which emit_jump
+                    // takes care of.
+                    self.emit_jump(out_idx0);
 
-                        comment!(self, "else");
-                        self.ir.push(BFQuad::Else(cond, else_tmp));
-                        self.block_to_ir(out_idx1);
+                    comment!(self, "else");
+                    self.ir.push(BFQuad::Else(cond, else_tmp));
+                    self.emit_jump(out_idx1);
 
-                        comment!(self, "end if");
-                        self.ir.push(BFQuad::EndElse(else_tmp));
-                    }
+                    comment!(self, "end if");
+                    self.ir.push(BFQuad::EndElse(else_tmp));
                     
                     self.alloc.free(cond);
                     self.alloc.free(else_tmp);
@@ -502,29 +645,474 @@ impl BlockToIR {
 
                 DBStmt::READ { ref varnames } => {
                     for (idx, vname) in varnames.iter().enumerate() {
-                        let expr = DBExpr::E(Expr::N(dbcmd.data[idx] as i32));
+                        let expr = match dbcmd.data[idx] {
+                            DBData::N(val) => DBExpr::E(Expr::D(val as f64)),
+                            DBData::S(ref txt) => DBExpr::S(txt.clone())
+                        };
                         self.emit_let(vname, &expr);
                     }
                 },
                 
-                DBStmt::PRINT { ref seq } => {
+                DBStmt::PRINT { ref seq, ref seps } => {
                     comment!(self, "Printing");
-                    for expr in seq {
-                        let code = self.ir_for_print(expr);
-                        self.ir.extend(code);
+                    for (idx, expr) in seq.iter().enumerate() {
+                        match *expr {
+                            DBExpr::E(Expr::F(ref name, ref args))
+                                if name == "TAB" || name == "SPC" => {
+                                let guard = self.open_running_guard();
+                                self.emit_print_spacing(name, args);
+                                self.close_guard(guard);
+                            },
+                            DBExpr::E(ref e) if e.is_string() => {
+                                // an earlier item might have stopped
+                                // the program
+                                let guard = self.open_running_guard();
+                                self.emit_string_print(e);
+                                self.close_guard(guard);
+                            },
+                            DBExpr::S(_) => {
+                                let guard = self.open_running_guard();
+                                let code = self.ir_for_print(expr);
+                                self.ir.extend(code);
+                                self.close_guard(guard);
+                            },
+                            _ => {
+                                let code = self.ir_for_print(expr);
+                                self.ir.extend(code);
+                            }
+                        }
+
+                        if self.options.classic_print
+                            && seps.get(idx) == Some(&DBPrintSep::Comma) {
+                            let guard = self.open_running_guard();
+                            let col = self.column.unwrap();
+                            self.emit_zone_pad(col);
+                            self.close_guard(guard);
+                        }
+                    }
+
+                    self.emit_print_end(seq, seps);
+                    comment!(self, "End of print");
+                },
+
+                DBStmt::PRINTUSING { ref format, ref seq, ref seps } => {
+                    comment!(self, "Printing with a format");
+                    let parts = parse_using(format);
+                    if !parts.iter().any(|p| matches!(*p, UsingPart::Field(..))) {
+                        panic!("PRINT USING format {:?} has no # fields", format);
+                    }
+
+                    // the format is used again from the start while
+                    // there are values left, and stops at the first
+                    // field without one
+                    let mut values = seq.iter().peekable();
+                    let mut idx = 0;
+                    loop {
+                        match parts[idx] {
+                            UsingPart::Text(ref text) => {
+                                let guard = self.open_running_guard();
+                                let code = self.ir_for_print(&DBExpr::S(text.clone()));
+                                self.ir.extend(code);
+                                self.close_guard(guard);
+                            },
+                            UsingPart::Field(width, zeros, places) => {
+                                match values.next() {
+                                    Some(expr) => self.emit_using_field(expr, width,
+                                                                        zeros, places),
+                                    None => break
+                                }
+                            }
+                        }
+
+                        idx += 1;
+                        if idx == parts.len() {
+                            if values.peek().is_none() {
+                                break;
+                            }
+                            idx = 0;
+                        }
                     }
+
+                    self.emit_print_end(seq, seps);
                     comment!(self, "End of print");
                 },
                 
                 _ => {}
             };
+
+            self.close_guard(guard);
         }
         
         return should_be_end;
     }
 
+    // a classic PRINT ends the line, unless it ends with a separator
+    fn emit_print_end(&mut self, seq: &[DBExpr], seps: &[DBPrintSep]) {
+        let ends_line = seq.is_empty() || seps.len() < seq.len();
+        if self.options.classic_print && ends_line {
+            let guard = self.open_running_guard();
+            let code = self.ir_for_print(&DBExpr::S(String::from("\n")));
+            self.ir.extend(code);
+            self.close_guard(guard);
+        }
+    }
+
+    // prints the spaces of a SPC(n) or TAB(n) item. TAB counts
+    // columns from 0, and prints nothing once the line is past n.
+    fn emit_print_spacing(&mut self, funcname: &str, args: &[Expr]) {
+        check_args(funcname, args, &[false]);
+        let (count, code) = self.ir_for_int_expr(&args[0]);
+        self.ir.extend(code);
+
+        if funcname == "TAB" {
+            let col = self.emit_copy(self.column.unwrap());
+            let t = self.alloc.reserve_range(3);
+            for i in t..t+3 {
+                self.ir.push(BFQuad::Zero(i));
+            }
+            self.ir.push(BFQuad::SubtractMinimum(count, col, t, t + 1, t + 2));
+            self.ir.push(BFQuad::Zero(col));
+            self.alloc.free(col);
+            for i in t..t+3 {
+                self.alloc.free(i);
+            }
+        }
+
+        self.emit_spaces(count);
+    }
+
+    // prints a number for PRINT USING, with the given places
+    // after the point
+    fn emit_using_field(&mut self, expr: &DBExpr, width: u32, zeros: u32,
+                        places: u32) {
+        let el = match *expr {
+            DBExpr::E(ref e) if !e.is_string() => {
+                let (el, code) = self.ir_for_expr(e);
+                self.ir.extend(code);
+                el
+            },
+            _ => panic!("PRINT USING can only format numbers!")
+        };
+
+        // the value is scaled to the places of the field, rounding
+        // any extra decimal places
+        let decimals = self.options.decimals;
+        let el = if places > decimals {
+            let dest = get_and_zero!(self);
+            self.ir.push(BFQuad::For(el));
+            self.ir.push(BFQuad::To(dest));
+            self.ir.push(BFQuad::Constant(10u32.pow(places - decimals)));
+            self.ir.push(BFQuad::Next(el));
+            self.alloc.free(el);
+            dest
+        } else if places < decimals {
+            let scale = 10u32.pow(decimals - places);
+            self.ir.push(BFQuad::To(el));
+            self.ir.push(BFQuad::Constant(scale / 2));
+            let (scale_loc, code) = self.ir_for_const(scale as i32);
+            self.ir.extend(code);
+            let (el, code) = self.ir_for_div(el, scale_loc);
+            self.ir.extend(code);
+            el
+        } else {
+            el
+        };
+
+        let guard = self.open_running_guard();
+        let t = self.alloc.reserve_range(22);
+        let column = self.column;
+        self.ir.push(BFQuad::PrintField(el, width, zeros, places, column, t));
+        for i in t..t+22 {
+            self.alloc.free(i);
+        }
+        self.close_guard(guard);
+        self.ir.push(BFQuad::Zero(el));
+        self.alloc.free(el);
+    }
+
+    // prints count spaces, consuming count
+    fn emit_spaces(&mut self, count: u32) {
+        let space = get_and_zero!(self);
+        if let Some(col) = self.column {
+            let tmp = get_and_zero!(self);
+            self.ir.push(BFQuad::AddTo(count, col, tmp));
+            self.alloc.free(tmp);
+        }
+
+        self.ir.push(BFQuad::To(space));
+        self.ir.push(BFQuad::Constant(32));
+        self.ir.push(BFQuad::For(count));
+        self.ir.push(BFQuad::To(space));
+        self.ir.push(BFQuad::RawBF("."));
+        self.ir.push(BFQuad::Next(count));
+        self.ir.push(BFQuad::Zero(space));
+        self.alloc.free(space);
+        self.alloc.free(count);
+    }
+
+    // prints spaces up to the start of the next print zone
+    fn emit_zone_pad(&mut self, col: u32) {
+        let t = self.alloc.reserve_range(7);
+        for i in t..t+7 {
+            self.ir.push(BFQuad::Zero(i));
+        }
+
+        // pad = PRINT_ZONE - col % PRINT_ZONE
+        let pad = get_and_zero!(self);
+        self.ir.push(BFQuad::AddTo(col, t, pad));
+        self.ir.push(BFQuad::To(t + 2));
+        self.ir.push(BFQuad::Constant(PRINT_ZONE));
+        self.ir.push(BFQuad::Div(t, t + 1, t + 2, t + 3, t + 4, t + 5, t + 6));
+        self.ir.push(BFQuad::To(pad));
+        self.ir.push(BFQuad::Constant(PRINT_ZONE));
+        self.ir.push(BFQuad::SubFrom(pad, t + 3));
+        for i in t..t+7 {
+            self.ir.push(BFQuad::Zero(i));
+            self.alloc.free(i);
+        }
+
+        self.emit_spaces(pad);
+    }
+
+    // emits the body of a loop or a subroutine, which has more code
+    // after it
+    fn emit_nested(&mut self, block: usize) {
+        self.nesting += 1;
+        self.block_to_ir(block);
+        self.nesting -= 1;
+    }
+
+    // emits the body of a FOR, WHILE or DO loop starting on line ln
+    fn emit_loop_body(&mut self, kind: &'static str, ln: u32, block: usize) {
+        self.open_loops.push((kind, ln));
+        self.emit_nested(block);
+        self.open_loops.pop();
+    }
+
+    // runs emit, which emits a subroutine. Its RETURN leaves the
+    // subroutine, not the loops around the GOSUB.
+    fn emit_subroutine<F>(&mut self, emit: F)
+        where F: FnOnce(&mut BlockToIR)
+    {
+        let outer_loops = mem::take(&mut self.open_loops);
+        emit(self);
+        self.open_loops = outer_loops;
+    }
+
+    // when the program can stop early, each statement only runs
+    // while it is still running
+    fn open_guard(&mut self, stmt: &DBStmt) -> Option<u32> {
+        match *stmt {
+            DBStmt::DEF { .. } | DBStmt::DIM { .. } | DBStmt::END
+                | DBStmt::STOP | DBStmt::NEXT { .. } | DBStmt::WEND | DBStmt::LOOP { .. }
+                | DBStmt::REM {..} => return None,
+            _ => {}
+        }
+
+        return self.open_running_guard();
+    }
+
+    fn open_running_guard(&mut self) -> Option<u32> {
+        let running = self.running?;
+        let guard = self.emit_copy(running);
+        self.ir.push(BFQuad::If(guard));
+        return Some(guard);
+    }
+
+    fn close_guard(&mut self, guard: Option<u32>) {
+        if let Some(guard) = guard {
+            self.ir.push(BFQuad::EndIf(guard));
+            self.alloc.free(guard);
+        }
+    }
+
+    // zeroes the condition of a loop once the program has stopped,
+    // so that the loop ends
+    fn emit_halt_check(&mut self, cond: u32) {
+        if let Some(running) = self.running {
+            let copy = self.emit_copy(running);
+            let halted = get_and_zero!(self);
+            self.ir.push(BFQuad::Not(copy, halted));
+            self.ir.push(BFQuad::If(halted));
+            self.ir.push(BFQuad::Zero(cond));
+            self.ir.push(BFQuad::EndIf(halted));
+            self.alloc.free(copy);
+            self.alloc.free(halted);
+        }
+    }
+
+    // prints an error naming the current line and stops the program.
+    // Only the first error is printed, even if the rest of the
+    // statement raises more.
+    fn ir_for_error(&mut self, msg: &str) -> Vec<BFQuad> {
+        let running = self.running
+            .expect("errors can only be raised when the program can stop");
+        let text = format!("{} IN LINE {}\n", msg, self.line);
+        return self.capture_ir(|this| {
+            let guard = this.open_running_guard();
+            let print = this.ir_for_print(&DBExpr::S(text));
+            this.ir.extend(print);
+            this.ir.push(BFQuad::Zero(running));
+            this.close_guard(guard);
+        });
+    }
+
+    // evaluates a comparison like X < 5, returning a cell that is
+    // non-zero iff the comparison holds.
+    fn emit_condition(&mut self, expr1: &DBExpr, op: &str,
+                      expr2: &DBExpr) -> u32 {
+        if expr1.is_string() || expr2.is_string() {
+            return self.emit_string_condition(expr1, op, expr2);
+        }
+        
+        let (loc1, expr1_code) = self.ir_for_expression(expr1);
+        let (loc2, expr2_code) = self.ir_for_expression(expr2);
+        
+        self.ir.extend(expr1_code);
+        self.ir.extend(expr2_code);
+        
+        let cond = get_and_zero!(self);
+        let t1 = get_and_zero!(self);
+        let t2 = get_and_zero!(self);
+        
+        let action = match op {
+            "="  => BFQuad::Equal(loc1, loc2, cond, t1, t2),
+            ">"  => BFQuad::Greater(loc1, loc2, cond, t1, t2),
+            "<"  => BFQuad::Less(loc1, loc2, cond, t1, t2),
+            "!=" => BFQuad::NotEqual(loc1, loc2, cond, t1, t2),
+            ">=" => BFQuad::GreaterOrEqual(loc1, loc2, cond, t1, t2),
+            "<=" => BFQuad::LessOrEqual(loc1, loc2, cond, t1, t2),
+            _ => panic!("unsupported relop")
+        };
+        
+        self.ir.push(action);
+        self.alloc.free(t1);
+        self.alloc.free(t2);
+        self.alloc.free(loc1);
+        self.alloc.free(loc2);
+        return cond;
+    }
+
+    // emits a jump to the given block, which might be the header of
+    // the current loop (nothing to do), one of its exits (mark the
+    // loop as done), or any other block (emit it inline).
+    fn emit_jump(&mut self, out_idx: usize) {
+        if let Some(&(block_idx, loop_var, cond_var)) = self.loop_stack.last() {
+            if block_idx == out_idx {
+                return;
+            }
+
+            let index = self.blocks[block_idx].loop_exits.iter()
+                .position(|&e| e == out_idx);
+
+            // an exit that ends the program is taken at once, since
+            // the rest of a loop body or subroutine around us would
+            // still run before the loop ends
+            if index.is_some() && self.nesting > 0 && self.ends_program(out_idx) {
+                self.block_to_ir(out_idx);
+                return;
+            }
+
+            if let Some(pos) = index {
+                mark_loop_done!(self, loop_var, cond_var, pos);
+                return;
+            }
+        }
+
+        self.block_to_ir(out_idx);
+    }
+
+    // whether the code starting at block always runs straight
+    // through to an END or STOP
+    fn ends_program(&self, block: usize) -> bool {
+        let mut idx = block;
+        let mut seen = HashSet::new();
+        while seen.insert(idx) {
+            let blk = &self.blocks[idx];
+            let last = match blk.cmds.last() {
+                Some(cmd) => &cmd.cmd,
+                None => return false
+            };
+
+            if last.is_end() {
+                return true;
+            }
+
+            let falls_through = match blk.special_out {
+                SpecialOut::None => blk.out_blocks.len() == 1,
+                _ => false
+            };
+            if blk.is_loop || !falls_through || matches!(*last, DBStmt::RETURN) {
+                return false;
+            }
+
+            idx = blk.out_blocks[0];
+        }
+
+        return false;
+    }
+
+    // emits an ON statement as a chain of equality checks: if the
+    // value is 1, jump to the first target, else if it is 2, jump to
+    // the second, and so on. Values out of range take the fallthrough
+    // block, if there is one.
+    fn emit_on_dispatch(&mut self, expr: &DBExpr, targets: &[usize],
+                        fallthrough: Option<usize>) {
+        comment!(self, "Start of ON dispatch");
+        let (loc, code) = self.ir_for_expression(expr);
+        self.ir.extend(code);
+        let (loc, code) = self.ir_for_unscale(loc);
+        self.ir.extend(code);
+
+        let mut open_branches = Vec::new();
+        for (idx, target) in targets.iter().enumerate() {
+            comment!(self, format!("ON branch {}", idx + 1));
+            let val = get_and_zero!(self);
+            let tmp = get_and_zero!(self);
+            self.ir.push(BFQuad::AddTo(loc, val, tmp));
+            self.alloc.free(tmp);
+
+            let (k, k_code) = self.ir_for_const(idx as i32 + 1);
+            self.ir.extend(k_code);
+
+            let cond = get_and_zero!(self);
+            let t1 = get_and_zero!(self);
+            let t2 = get_and_zero!(self);
+            self.ir.push(BFQuad::Equal(val, k, cond, t1, t2));
+            self.alloc.free(t1);
+            self.alloc.free(t2);
+            self.alloc.free(val);
+            self.alloc.free(k);
+
+            let else_tmp = get_and_zero!(self);
+            self.ir.push(BFQuad::IfElse(cond, else_tmp));
+            self.emit_jump(*target);
+            self.ir.push(BFQuad::Else(cond, else_tmp));
+            open_branches.push((cond, else_tmp));
+        }
+
+        if let Some(out_idx) = fallthrough {
+            comment!(self, "ON value out of range");
+            self.emit_jump(out_idx);
+        }
+
+        for (cond, else_tmp) in open_branches.into_iter().rev() {
+            self.ir.push(BFQuad::EndElse(else_tmp));
+            self.alloc.free(cond);
+            self.alloc.free(else_tmp);
+        }
+        
+        self.alloc.free(loc);
+        comment!(self, "End of ON dispatch");
+    }
+
     fn emit_let(&mut self, target: &DBLetTarget, expr: &DBExpr) {
         match *target {
+            DBLetTarget::VAR (ref varname) if varname.ends_with('$') => {
+                comment!(self, format!("LET for string {}", varname));
+                self.emit_string_let(varname, expr);
+            },
+            
             DBLetTarget::VAR (ref varname) => {
                 comment!(self, format!("LET for variable {}", varname));
                 if !self.symbol_t.contains_key(varname) {
@@ -545,8 +1133,6 @@ impl BlockToIR {
                 comment!(self, format!("LET for array {}",
                                        indexing_expressions.varname));
 
-                self.already_used_array = true;
-                
                 let (arr_pos, arr_idx, idx_code)
                     = self.compute_array_index(indexing_expressions);
                 
@@ -570,6 +1156,491 @@ impl BlockToIR {
         
     }
 
+    // copies the value in src into a new cell, leaving src alone
+    fn emit_copy(&mut self, src: u32) -> u32 {
+        let dest = get_and_zero!(self);
+        let tmp = get_and_zero!(self);
+        self.ir.push(BFQuad::AddTo(src, dest, tmp));
+        self.alloc.free(tmp);
+        return dest;
+    }
+
+    fn get_string(&mut self, varname: &str) -> u32 {
+        if !self.string_t.contains_key(varname) {
+            // fresh cells are zero, so new strings start out empty.
+            let pos = self.alloc.reserve_array(STRING_CAPACITY + 1);
+            self.string_t.insert(String::from(varname), pos);
+        }
+
+        return self.string_t[varname];
+    }
+
+    // reserves an empty string to hold an intermediate result. The
+    // cells may be reused on each trip through a loop, so they have
+    // to be cleared first.
+    fn reserve_temp_string(&mut self) -> u32 {
+        let pos = self.alloc.reserve_array(STRING_CAPACITY + 1);
+        for i in pos..pos+4 {
+            self.ir.push(BFQuad::Zero(i));
+        }
+        self.emit_string_clear(pos);
+        return pos;
+    }
+
+    fn free_temp_string(&mut self, pos: u32) {
+        self.alloc.free_array(pos, STRING_CAPACITY + 1);
+    }
+
+    // emits a string routine, giving it the scratch cells it needs
+    fn emit_string_routine<F>(&mut self, routine: F)
+        where F: FnOnce(u32) -> BFQuad
+    {
+        let scratch = self.alloc.reserve_range(STRING_SCRATCH);
+        self.ir.push(routine(scratch));
+        for i in scratch..scratch+STRING_SCRATCH {
+            self.alloc.free(i);
+        }
+    }
+
+    fn emit_string_len(&mut self, string: u32) -> u32 {
+        let len = get_and_zero!(self);
+        self.emit_string_routine(|t| BFQuad::StrLen(string, len, t));
+        return len;
+    }
+
+    // reads the character at pos, which starts from 1
+    fn emit_string_char(&mut self, string: u32, pos: u32) -> u32 {
+        let chr = get_and_zero!(self);
+        self.emit_string_routine(|t| BFQuad::StrChar(string, pos, chr, t));
+        return chr;
+    }
+
+    fn emit_string_clear(&mut self, string: u32) {
+        self.emit_string_routine(|t| BFQuad::StrClear(string, t));
+    }
+
+    // adds the character in chr to the end of the string, consuming
+    // chr. Characters past the capacity of the string are dropped.
+    fn emit_string_push(&mut self, string: u32, chr: u32) {
+        self.emit_string_routine(|t| BFQuad::StrPush(string, chr, t));
+        self.alloc.free(chr);
+    }
+
+    // evaluates the string expression expr onto the end of dest
+    fn emit_string_append(&mut self, dest: u32, expr: &Expr) {
+        match *expr {
+            Expr::S(ref txt) => {
+                for chr in txt.chars() {
+                    let (loc, code) = self.ir_for_const(chr as i32);
+                    self.ir.extend(code);
+                    self.emit_string_push(dest, loc);
+                }
+            },
+
+            Expr::V(ref varname) => {
+                let src = self.get_string(varname);
+                self.emit_string_routine(|t| BFQuad::StrAppend(dest, src, t));
+            },
+
+            Expr::O(ref e1, OpCode::Add, ref e2) => {
+                self.emit_string_append(dest, e1);
+                self.emit_string_append(dest, e2);
+            },
+
+            Expr::E(ref e) => {
+                self.emit_string_append(dest, e);
+            },
+
+            Expr::F(ref funcname, ref args) => {
+                self.emit_string_function(dest, funcname, args);
+            },
+
+            Expr::O(..) => panic!("Only + is supported on strings!"),
+            _ => panic!("Found number in string expression!")
+        }
+    }
+
+    // appends the result of a string function, like MID$(A$, 2, 3),
+    // to the end of dest
+    fn emit_string_function(&mut self, dest: u32, funcname: &str, args: &[Expr]) {
+        comment!(self, format!("Calling {}", funcname));
+        match funcname {
+            "CHR$" => {
+                check_args(funcname, args, &[false]);
+                let (chr, code) = self.ir_for_int_expr(&args[0]);
+                self.ir.extend(code);
+                self.emit_string_push(dest, chr);
+            },
+
+            "STR$" => {
+                check_args(funcname, args, &[false]);
+                let (num, code) = self.ir_for_expr(&args[0]);
+                self.ir.extend(code);
+                let decimals = self.options.decimals;
+                self.emit_string_routine(|t| BFQuad::StrNum(dest, num, decimals, t));
+                self.alloc.free(num);
+            },
+
+            "LEFT$" => {
+                check_args(funcname, args, &[true, false]);
+                let (src, is_temp) = self.emit_string_operand(&args[0]);
+                let (count, code) = self.ir_for_int_expr(&args[1]);
+                self.ir.extend(code);
+                let (from, code) = self.ir_for_const(1);
+                self.ir.extend(code);
+                
+                self.emit_slice(dest, src, from, count);
+                if is_temp {
+                    self.free_temp_string(src);
+                }
+            },
+
+            "RIGHT$" => {
+                check_args(funcname, args, &[true, false]);
+                let (src, is_temp) = self.emit_string_operand(&args[0]);
+                let (count, code) = self.ir_for_int_expr(&args[1]);
+                self.ir.extend(code);
+                
+                // start after the first len - min(len, count) characters
+                let from = self.emit_string_len(src);
+                let count_copy = self.emit_copy(count);
+                let t1 = get_and_zero!(self);
+                let t2 = get_and_zero!(self);
+                let t3 = get_and_zero!(self);
+                self.ir.push(BFQuad::SubtractMinimum(from, count_copy,
+                                                     t1, t2, t3));
+                self.ir.push(BFQuad::Zero(count_copy));
+                self.ir.push(BFQuad::To(from));
+                self.ir.push(BFQuad::RawBF("+"));
+                self.alloc.free(count_copy);
+                self.alloc.free(t1);
+                self.alloc.free(t2);
+                self.alloc.free(t3);
+                
+                self.emit_slice(dest, src, from, count);
+                if is_temp {
+                    self.free_temp_string(src);
+                }
+            },
+
+            "MID$" => {
+                if args.len() == 2 {
+                    check_args(funcname, args, &[true, false]);
+                } else {
+                    check_args(funcname, args, &[true, false, false]);
+                }
+                
+                let (src, is_temp) = self.emit_string_operand(&args[0]);
+                let (from, code) = self.ir_for_int_expr(&args[1]);
+                self.ir.extend(code);
+
+                // positions start at 1, so treat 0 as 1
+                let from_copy = self.emit_copy(from);
+                let from_zero = get_and_zero!(self);
+                self.ir.push(BFQuad::Not(from_copy, from_zero));
+                self.ir.push(BFQuad::Move(from_zero, from));
+                self.alloc.free(from_copy);
+                self.alloc.free(from_zero);
+
+                // without a count, take the rest of the string
+                let count = if args.len() == 3 {
+                    let (count, code) = self.ir_for_int_expr(&args[2]);
+                    self.ir.extend(code);
+                    count
+                } else {
+                    self.emit_string_len(src)
+                };
+                
+                self.emit_slice(dest, src, from, count);
+                if is_temp {
+                    self.free_temp_string(src);
+                }
+            },
+
+            _ => panic!("Found number in string expression!")
+        }
+    }
+
+    // appends count characters of src, starting at from, to dest.
+    // Consumes from and count.
+    fn emit_slice(&mut self, dest: u32, src: u32, from: u32, count: u32) {
+        self.emit_string_routine(|t| BFQuad::StrSlice(dest, src, from, count, t));
+        self.alloc.free(from);
+        self.alloc.free(count);
+    }
+
+    // evaluates a function that takes a string and returns a number,
+    // like LEN(A$)
+    fn emit_numeric_function(&mut self, funcname: &str, args: &[Expr]) -> u32 {
+        comment!(self, format!("Calling {}", funcname));
+        if funcname == "TAB" || funcname == "SPC" {
+            panic!("{} can only be used in a PRINT statement!", funcname);
+        }
+        check_args(funcname, args, &[true]);
+        let (src, is_temp) = self.emit_string_operand(&args[0]);
+
+        let res = match funcname {
+            "LEN" => self.emit_string_len(src),
+            "ASC" => {
+                let res = get_and_zero!(self);
+                self.emit_string_routine(|t| BFQuad::StrAsc(src, res, t));
+                res
+            },
+            "VAL" => {
+                // VAL reads the decimal places itself
+                let res = get_and_zero!(self);
+                let decimals = self.options.decimals;
+                self.emit_string_routine(|t| BFQuad::StrVal(src, res, decimals, t));
+                if is_temp {
+                    self.free_temp_string(src);
+                }
+                return res;
+            },
+            _ => panic!("Found string in mathematical expression!")
+        };
+        let (res, code) = self.ir_for_scale(res);
+        self.ir.extend(code);
+
+        if is_temp {
+            self.free_temp_string(src);
+        }
+        return res;
+    }
+
+    fn emit_string_let(&mut self, varname: &str, expr: &DBExpr) {
+        if !expr.is_string() {
+            panic!("Cannot assign a number to string {}!", varname);
+        }
+
+        let expr = to_string_expr(expr);
+        let dest = self.get_string(varname);
+
+        if mentions_var(&expr, varname) {
+            // something like A$ = "x" + A$ must not clobber A$
+            // while reading it.
+            let tmp = self.reserve_temp_string();
+            self.emit_string_append(tmp, &expr);
+            self.emit_string_clear(dest);
+            self.emit_string_routine(|t| BFQuad::StrAppend(dest, tmp, t));
+            self.free_temp_string(tmp);
+        } else {
+            self.emit_string_clear(dest);
+            self.emit_string_append(dest, &expr);
+        }
+        
+        comment!(self, "End of string LET");
+    }
+
+    fn emit_string_print(&mut self, expr: &Expr) {
+        match *expr {
+            Expr::S(ref txt) => {
+                let code = self.ir_for_print(&DBExpr::S(txt.clone()));
+                self.ir.extend(code);
+            },
+
+            Expr::O(ref e1, OpCode::Add, ref e2) => {
+                self.emit_string_print(e1);
+                self.emit_string_print(e2);
+            },
+
+            Expr::E(ref e) => {
+                self.emit_string_print(e);
+            },
+
+            Expr::F(ref funcname, ref args) if funcname == "CHR$" => {
+                // no need to build a string for a single character
+                check_args(funcname, args, &[false]);
+                let (chr, code) = self.ir_for_int_expr(&args[0]);
+                self.ir.extend(code);
+                self.ir.push(BFQuad::To(chr));
+                self.ir.push(BFQuad::RawBF("."));
+                self.ir.push(BFQuad::Zero(chr));
+                self.alloc.free(chr);
+
+                if let Some(col) = self.column {
+                    self.ir.push(BFQuad::To(col));
+                    self.ir.push(BFQuad::RawBF("+"));
+                }
+            },
+
+            _ => {
+                let (string, is_temp) = self.emit_string_operand(expr);
+                if let Some(col) = self.column {
+                    self.emit_string_routine(|t| BFQuad::StrLen(string, col, t));
+                }
+                self.emit_string_routine(|t| BFQuad::StrPrint(string, t));
+                if is_temp {
+                    self.free_temp_string(string);
+                }
+            }
+        }
+    }
+
+    // gets a string holding the value of expr, which is a temporary
+    // string (the second value is true) unless expr is a variable.
+    fn emit_string_operand(&mut self, expr: &Expr) -> (u32, bool) {
+        if !expr.is_string() {
+            panic!("Found number in string expression!");
+        }
+        
+        if let Expr::V(ref varname) = *expr {
+            return (self.get_string(varname), false);
+        }
+
+        let tmp = self.reserve_temp_string();
+        self.emit_string_append(tmp, expr);
+        return (tmp, true);
+    }
+
+    // runs emit, which pushes code onto self.ir, and returns that
+    // code instead.
+    fn capture_ir<F>(&mut self, emit: F) -> Vec<BFQuad>
+        where F: FnOnce(&mut BlockToIR)
+    {
+        let saved = mem::take(&mut self.ir);
+        emit(self);
+        return mem::replace(&mut self.ir, saved);
+    }
+
+    // compares two strings in dictionary order. Returns a cell that
+    // is non-zero iff the comparison holds.
+    fn emit_string_condition(&mut self, expr1: &DBExpr, op: &str,
+                             expr2: &DBExpr) -> u32 {
+        if !expr1.is_string() || !expr2.is_string() {
+            panic!("Cannot compare a string to a number!");
+        }
+        
+        comment!(self, "String comparison");
+        let (s1, tmp1) = self.emit_string_operand(&to_string_expr(expr1));
+        let (s2, tmp2) = self.emit_string_operand(&to_string_expr(expr2));
+
+        // lt and gt are set by the first differing character, or,
+        // if one string is a prefix of the other, by the lengths.
+        let lt = get_and_zero!(self);
+        let gt = get_and_zero!(self);
+        let decided = get_and_zero!(self);
+
+        // after subtracting the shorter length from both lengths,
+        // whichever is left over is the longer string.
+        let longer1 = self.emit_string_len(s1);
+        let longer2 = self.emit_string_len(s2);
+        let t1 = get_and_zero!(self);
+        let t2 = get_and_zero!(self);
+        let t3 = get_and_zero!(self);
+        self.ir.push(BFQuad::SubtractMinimum(longer1, longer2, t1, t2, t3));
+
+        let count = self.emit_string_len(s1);
+        let longer1_copy = self.emit_copy(longer1);
+        self.ir.push(BFQuad::SubFrom(count, longer1_copy));
+        self.alloc.free(longer1_copy);
+
+        let pos = get_and_zero!(self);
+        self.ir.push(BFQuad::For(count));
+        self.ir.push(BFQuad::To(pos));
+        self.ir.push(BFQuad::RawBF("+"));
+        let c1 = self.emit_string_char(s1, pos);
+        let c2 = self.emit_string_char(s2, pos);
+
+        let decided_copy = self.emit_copy(decided);
+        let undecided = get_and_zero!(self);
+        self.ir.push(BFQuad::Not(decided_copy, undecided));
+        self.ir.push(BFQuad::If(undecided));
+        {
+            let c1_copy = self.emit_copy(c1);
+            let c2_copy = self.emit_copy(c2);
+            let greater = get_and_zero!(self);
+            let less = get_and_zero!(self);
+            self.ir.push(BFQuad::Greater(c1, c2, greater, t1, t2));
+            self.ir.push(BFQuad::Less(c1_copy, c2_copy, less, t1, t2));
+            self.alloc.free(c1_copy);
+            self.alloc.free(c2_copy);
+
+            for &(flag, res) in [(greater, gt), (less, lt)].iter() {
+                self.ir.push(BFQuad::If(flag));
+                self.ir.push(BFQuad::Zero(res));
+                self.ir.push(BFQuad::RawBF("+"));
+                self.ir.push(BFQuad::Zero(decided));
+                self.ir.push(BFQuad::RawBF("+"));
+                self.ir.push(BFQuad::EndIf(flag));
+                self.alloc.free(flag);
+            }
+        }
+        self.ir.push(BFQuad::EndIf(undecided));
+        self.ir.push(BFQuad::Zero(c1));
+        self.ir.push(BFQuad::Zero(c2));
+        self.alloc.free(decided_copy);
+        self.alloc.free(undecided);
+        self.alloc.free(c1);
+        self.alloc.free(c2);
+        self.ir.push(BFQuad::Next(count));
+        self.alloc.free(count);
+        self.alloc.free(pos);
+
+        // all the characters matched, so the longer string is greater
+        let undecided = get_and_zero!(self);
+        self.ir.push(BFQuad::Not(decided, undecided));
+        self.ir.push(BFQuad::If(undecided));
+        for &(flag, res) in [(longer1, gt), (longer2, lt)].iter() {
+            self.ir.push(BFQuad::If(flag));
+            self.ir.push(BFQuad::Zero(res));
+            self.ir.push(BFQuad::RawBF("+"));
+            self.ir.push(BFQuad::EndIf(flag));
+        }
+        self.ir.push(BFQuad::EndIf(undecided));
+        self.ir.push(BFQuad::Zero(longer1));
+        self.ir.push(BFQuad::Zero(longer2));
+        self.alloc.free(undecided);
+        self.alloc.free(decided);
+        self.alloc.free(longer1);
+        self.alloc.free(longer2);
+        self.alloc.free(t1);
+        self.alloc.free(t2);
+        self.alloc.free(t3);
+
+        let cond = get_and_zero!(self);
+        match op {
+            "<" => {
+                self.ir.push(BFQuad::Move(lt, cond));
+                self.ir.push(BFQuad::Zero(gt));
+            },
+            ">" => {
+                self.ir.push(BFQuad::Move(gt, cond));
+                self.ir.push(BFQuad::Zero(lt));
+            },
+            "<=" => {
+                self.ir.push(BFQuad::Not(gt, cond));
+                self.ir.push(BFQuad::Zero(lt));
+            },
+            ">=" => {
+                self.ir.push(BFQuad::Not(lt, cond));
+                self.ir.push(BFQuad::Zero(gt));
+            },
+            "!=" => {
+                self.ir.push(BFQuad::Or(lt, gt, cond));
+            },
+            "=" => {
+                let differ = get_and_zero!(self);
+                self.ir.push(BFQuad::Or(lt, gt, differ));
+                self.ir.push(BFQuad::Not(differ, cond));
+                self.alloc.free(differ);
+            },
+            _ => panic!("unsupported relop")
+        };
+        self.alloc.free(lt);
+        self.alloc.free(gt);
+
+        if tmp1 {
+            self.free_temp_string(s1);
+        }
+
+        if tmp2 {
+            self.free_temp_string(s2);
+        }
+        
+        comment!(self, "End of string comparison");
+        return cond;
+    }
+
     fn compute_array_index(&mut self, indexing_expressions: &DBArrayDef)
                            -> (u32, u32, Vec<BFQuad>)
     {
@@ -585,11 +1656,42 @@ impl BlockToIR {
         let mut dim_indexes = Vec::new();
 
         for dim in indexing_expressions.dims.iter() {
-            let (pos, code) = self.ir_for_expr(dim);
+            let (pos, code) = self.ir_for_int_expr(dim);
             dim_indexes.push(pos);
             to_r.extend(code);
         }
 
+        // in checked mode, count the indexes that are out of range
+        let out_of_range = if self.options.check_bounds {
+            let bad = self.alloc.reserve();
+            to_r.push(BFQuad::Zero(bad));
+            for (dim_idx, size) in dim_indexes.iter().zip(adef.iter()) {
+                let copy = self.alloc.reserve();
+                let res = self.alloc.reserve();
+                let t1 = self.alloc.reserve();
+                let t2 = self.alloc.reserve();
+                to_r.push(BFQuad::Zero(copy));
+                to_r.push(BFQuad::Zero(res));
+                to_r.push(BFQuad::Zero(t1));
+                to_r.push(BFQuad::Zero(t2));
+                to_r.push(BFQuad::AddTo(*dim_idx, copy, t1));
+
+                let (size_pos, size_code) = self.ir_for_const(*size as i32);
+                to_r.extend(size_code);
+                to_r.push(BFQuad::GreaterOrEqual(copy, size_pos, res, t1, t2));
+                to_r.push(BFQuad::Move(res, bad));
+
+                self.alloc.free(size_pos);
+                self.alloc.free(copy);
+                self.alloc.free(res);
+                self.alloc.free(t1);
+                self.alloc.free(t2);
+            }
+            Some(bad)
+        } else {
+            None
+        };
+
         // if I have a 5x6 array and I want to access element
         // 2, 3, then I need to compute 2 * 6 + 3.
         // if I have an 5x6x7 array and I want to access element
@@ -623,6 +1725,17 @@ impl BlockToIR {
             self.alloc.free(*dim_idx);
         }
 
+        // a bad index reads or writes the first element instead,
+        // since the program stops anyway
+        if let Some(bad) = out_of_range {
+            to_r.push(BFQuad::If(bad));
+            to_r.push(BFQuad::Zero(accum));
+            let error_code = self.ir_for_error("SUBSCRIPT OUT OF RANGE");
+            to_r.extend(error_code);
+            to_r.push(BFQuad::EndIf(bad));
+            self.alloc.free(bad);
+        }
+
         return (arr_pos, accum, to_r);
     }
     
@@ -630,6 +1743,9 @@ impl BlockToIR {
 
         match *expr {
             DBExpr::S (_) => panic!("Found string in mathematical expression!"),
+            DBExpr::E ( ref expr ) if expr.is_string() => {
+                panic!("Found string in mathematical expression!");
+            },
             DBExpr::E ( ref expr ) => {
                 return self.ir_for_expr(expr);
             }
@@ -646,6 +1762,11 @@ impl BlockToIR {
 
                 to_r.extend(e1c);
                 to_r.extend(e2c);
+
+                if self.options.check_arithmetic {
+                    let check_code = self.ir_for_arith_check(op, e1l, e2l);
+                    to_r.extend(check_code);
+                }
                 
                 match *op {
                     OpCode::Add => {
@@ -674,51 +1795,51 @@ impl BlockToIR {
                         self.alloc.free(e1l);
                         self.alloc.free(e2l);
                         self.alloc.free(tmp);
+
+                        if self.options.decimals == 0 {
+                            return (loc, to_r);
+                        }
+
+                        // the product has twice the decimal places,
+                        // so divide by the scale, rounding.
+                        let scale = self.scale();
+                        to_r.push(BFQuad::To(loc));
+                        to_r.push(BFQuad::Constant(scale / 2));
+                        let (scale_loc, scale_code) = self.ir_for_const(scale as i32);
+                        to_r.extend(scale_code);
+                        let (loc, div_code) = self.ir_for_div(loc, scale_loc);
+                        to_r.extend(div_code);
                         return (loc, to_r);
                     },
 
-                    OpCode::Div => {
-                        let tmp_start = self.alloc.reserve_range(7);
-                        //quo, t1, div, rem, res, t3, t4
-
-                        let t1 = self.alloc.reserve();
-
-                        to_r.push(BFQuad::Zero(tmp_start)); // + 0
-                        to_r.push(BFQuad::Zero(tmp_start + 1));
-                        to_r.push(BFQuad::Zero(tmp_start + 2));
-                        to_r.push(BFQuad::Zero(tmp_start + 3));
-                        to_r.push(BFQuad::Zero(tmp_start + 4));
-                        to_r.push(BFQuad::Zero(tmp_start + 5));
-                        to_r.push(BFQuad::Zero(tmp_start + 6));
-
-                        to_r.push(BFQuad::AddTo(e1l, tmp_start, t1));
-                        to_r.push(BFQuad::Zero(t1));
-                        to_r.push(BFQuad::AddTo(e2l, tmp_start + 2, t1));
-
-                        to_r.push(BFQuad::Div(tmp_start, // + 0
-                                              tmp_start + 1,
-                                              tmp_start + 2,
-                                              tmp_start + 3,
-                                              tmp_start + 4,
-                                              tmp_start + 5,
-                                              tmp_start + 6));
+                    OpCode::Div if self.options.decimals > 0 => {
+                        // scale up the dividend to keep the decimal
+                        // places, and add half the divisor to round.
+                        let (e1l, scale_code) = self.ir_for_scale(e1l);
+                        to_r.extend(scale_code);
 
-                        let loc = self.alloc.reserve();
-                        to_r.push(BFQuad::Zero(t1));
-                        to_r.push(BFQuad::AddTo(tmp_start + 4,
-                                                loc, t1));
-
-                        self.alloc.free(t1);
-                        self.alloc.free(tmp_start); // + 0
-                        self.alloc.free(tmp_start + 1);
-                        self.alloc.free(tmp_start + 2);
-                        self.alloc.free(tmp_start + 3);
-                        self.alloc.free(tmp_start + 4);
-                        self.alloc.free(tmp_start + 5);
-                        self.alloc.free(tmp_start + 6);
-                        self.alloc.free(e1l);
-                        self.alloc.free(e2l);
-                        return (loc, to_r);          
+                        let half = self.alloc.reserve();
+                        let tmp = self.alloc.reserve();
+                        to_r.push(BFQuad::Zero(half));
+                        to_r.push(BFQuad::Zero(tmp));
+                        to_r.push(BFQuad::AddTo(e2l, half, tmp));
+                        self.alloc.free(tmp);
+                        let (two, two_code) = self.ir_for_const(2);
+                        to_r.extend(two_code);
+                        let (half, half_code) = self.ir_for_div(half, two);
+                        to_r.extend(half_code);
+                        to_r.push(BFQuad::Move(half, e1l));
+                        self.alloc.free(half);
+
+                        let (loc, div_code) = self.ir_for_div(e1l, e2l);
+                        to_r.extend(div_code);
+                        return (loc, to_r);
+                    },
+
+                    OpCode::Div => {
+                        let (loc, div_code) = self.ir_for_div(e1l, e2l);
+                        to_r.extend(div_code);
+                        return (loc, to_r);
                     }
 
                 }
@@ -726,13 +1847,22 @@ impl BlockToIR {
 
             // N V A E
             Expr::N(ref t) => {
-                return self.ir_for_const(*t);
+                return self.ir_for_const(*t * self.scale() as i32);
+            },
+
+            Expr::D(val) => {
+                let val = self.fixed_value(val);
+                return self.ir_for_const(val);
             },
 
             Expr::V(ref vname) => {
                 return self.ir_for_var(vname);
             },
 
+            Expr::A(ref call) if self.def_map.contains_key(&call.varname) => {
+                return self.ir_for_call(call);
+            },
+
             Expr::A(ref array_dim) => {
                 let mut to_r = Vec::new();
                 let indexing_expressions = array_dim;
@@ -750,6 +1880,18 @@ impl BlockToIR {
 
             Expr::E(ref e) => {
                 return self.ir_for_expr(e);
+            },
+
+            Expr::S(_) => {
+                panic!("Found string in mathematical expression!");
+            },
+
+            Expr::F(ref funcname, ref args) => {
+                let mut loc = 0;
+                let code = self.capture_ir(|this| {
+                    loc = this.emit_numeric_function(funcname, args);
+                });
+                return (loc, code);
             }
 
             
@@ -757,6 +1899,186 @@ impl BlockToIR {
         }
     }
 
+    // in checked mode, stops the program if e1l - e2l would be
+    // negative or e2l is a zero divisor. The operands are then
+    // changed so that the operation itself can't hang or underflow.
+    fn ir_for_arith_check(&mut self, op: &OpCode, e1l: u32, e2l: u32)
+                          -> Vec<BFQuad> {
+        let mut to_r = Vec::new();
+        let bad = self.alloc.reserve();
+        let copy = self.alloc.reserve();
+        let tmp = self.alloc.reserve();
+        to_r.push(BFQuad::Zero(bad));
+        to_r.push(BFQuad::Zero(copy));
+        to_r.push(BFQuad::Zero(tmp));
+        to_r.push(BFQuad::AddTo(e2l, copy, tmp));
+
+        let msg = match *op {
+            OpCode::Sub => {
+                let copy1 = self.alloc.reserve();
+                let t2 = self.alloc.reserve();
+                to_r.push(BFQuad::Zero(copy1));
+                to_r.push(BFQuad::Zero(t2));
+                to_r.push(BFQuad::AddTo(e1l, copy1, tmp));
+                to_r.push(BFQuad::Greater(copy, copy1, bad, tmp, t2));
+                self.alloc.free(copy1);
+                self.alloc.free(t2);
+                "NEGATIVE RESULT"
+            },
+            OpCode::Div => {
+                to_r.push(BFQuad::Not(copy, bad));
+                "DIVISION BY ZERO"
+            },
+            _ => {
+                self.alloc.free(bad);
+                self.alloc.free(copy);
+                self.alloc.free(tmp);
+                return to_r;
+            }
+        };
+        self.alloc.free(copy);
+        self.alloc.free(tmp);
+
+        to_r.push(BFQuad::If(bad));
+        let error_code = self.ir_for_error(msg);
+        to_r.extend(error_code);
+        to_r.push(BFQuad::Zero(e2l));
+        if let OpCode::Div = *op {
+            to_r.push(BFQuad::To(e2l));
+            to_r.push(BFQuad::RawBF("+"));
+        }
+        to_r.push(BFQuad::EndIf(bad));
+        self.alloc.free(bad);
+        return to_r;
+    }
+
+    // a call to a DEF function is its body, with the argument in
+    // place of the function's variable
+    fn ir_for_call(&mut self, call: &DBArrayDef) -> (u32, Vec<BFQuad>) {
+        let name = &call.varname;
+        if call.dims.len() != 1 {
+            panic!("Function {} takes 1 argument, but was called with {}",
+                   name, call.dims.len());
+        }
+
+        if self.calls.contains(name) {
+            panic!("Function {} calls itself", name);
+        }
+
+        let (varname, body) = self.def_map[name].clone();
+        let body = match body {
+            DBExpr::E(ref e) if !e.is_string() => {
+                replace_var(e, &varname, &call.dims[0])
+            },
+            _ => panic!("Function {} must give a number", name)
+        };
+
+        self.calls.push(name.clone());
+        let res = self.ir_for_expr(&body);
+        self.calls.pop();
+        return res;
+    }
+
+    // divides e1l by e2l, rounding down, and frees them both
+    fn ir_for_div(&mut self, e1l: u32, e2l: u32) -> (u32, Vec<BFQuad>) {
+        let mut to_r = Vec::new();
+        let tmp_start = self.alloc.reserve_range(7);
+        //quo, t1, div, rem, res, t3, t4
+
+        let t1 = self.alloc.reserve();
+        to_r.push(BFQuad::Zero(t1));
+
+        to_r.push(BFQuad::Zero(tmp_start)); // + 0
+        to_r.push(BFQuad::Zero(tmp_start + 1));
+        to_r.push(BFQuad::Zero(tmp_start + 2));
+        to_r.push(BFQuad::Zero(tmp_start + 3));
+        to_r.push(BFQuad::Zero(tmp_start + 4));
+        to_r.push(BFQuad::Zero(tmp_start + 5));
+        to_r.push(BFQuad::Zero(tmp_start + 6));
+
+        to_r.push(BFQuad::AddTo(e1l, tmp_start, t1));
+        to_r.push(BFQuad::Zero(t1));
+        to_r.push(BFQuad::AddTo(e2l, tmp_start + 2, t1));
+
+        to_r.push(BFQuad::Div(tmp_start, // + 0
+                              tmp_start + 1,
+                              tmp_start + 2,
+                              tmp_start + 3,
+                              tmp_start + 4,
+                              tmp_start + 5,
+                              tmp_start + 6));
+
+        let loc = self.alloc.reserve();
+        to_r.push(BFQuad::Zero(loc));
+        to_r.push(BFQuad::Zero(t1));
+        to_r.push(BFQuad::AddTo(tmp_start + 4,
+                                loc, t1));
+
+        self.alloc.free(t1);
+        self.alloc.free(tmp_start); // + 0
+        self.alloc.free(tmp_start + 1);
+        self.alloc.free(tmp_start + 2);
+        self.alloc.free(tmp_start + 3);
+        self.alloc.free(tmp_start + 4);
+        self.alloc.free(tmp_start + 5);
+        self.alloc.free(tmp_start + 6);
+        self.alloc.free(e1l);
+        self.alloc.free(e2l);
+        return (loc, to_r);
+    }
+
+    fn scale(&self) -> u32 {
+        return 10u32.pow(self.options.decimals);
+    }
+
+    // the cell value of a number, which is scaled in fixed-point mode.
+    // Negative values become zero, and integer mode truncates.
+    fn fixed_value(&self, val: f64) -> i32 {
+        if self.options.decimals == 0 {
+            return val as u32 as i32;
+        }
+
+        return (val * self.scale() as f64).round() as u32 as i32;
+    }
+
+    // multiplies the value in loc by the scale, freeing loc
+    fn ir_for_scale(&mut self, loc: u32) -> (u32, Vec<BFQuad>) {
+        if self.options.decimals == 0 {
+            return (loc, Vec::new());
+        }
+
+        let dest = self.alloc.reserve();
+        let to_r = vec![BFQuad::Zero(dest),
+                        BFQuad::For(loc),
+                        BFQuad::To(dest),
+                        BFQuad::Constant(self.scale()),
+                        BFQuad::Next(loc)];
+        self.alloc.free(loc);
+        return (dest, to_r);
+    }
+
+    // drops the decimal places of the value in loc, freeing loc
+    fn ir_for_unscale(&mut self, loc: u32) -> (u32, Vec<BFQuad>) {
+        if self.options.decimals == 0 {
+            return (loc, Vec::new());
+        }
+
+        let scale = self.scale() as i32;
+        let (scale_loc, mut to_r) = self.ir_for_const(scale);
+        let (dest, div_code) = self.ir_for_div(loc, scale_loc);
+        to_r.extend(div_code);
+        return (dest, to_r);
+    }
+
+    // evaluates an expression used as a whole number, like an array
+    // index
+    fn ir_for_int_expr(&mut self, expr: &Expr) -> (u32, Vec<BFQuad>) {
+        let (loc, mut to_r) = self.ir_for_expr(expr);
+        let (loc, code) = self.ir_for_unscale(loc);
+        to_r.extend(code);
+        return (loc, to_r);
+    }
+
     fn ir_for_const(&mut self, val: i32) -> (u32, Vec<BFQuad>) {
         let mut to_r = Vec::new();
         if val < 0 {
@@ -786,6 +2108,10 @@ impl BlockToIR {
     }
 
     fn ir_for_var(&mut self, varname: &str) -> (u32, Vec<BFQuad>) {
+        if varname.ends_with('$') {
+            panic!("Found string in mathematical expression!");
+        }
+        
         let mut to_r = Vec::new();
         let varloc = *(self.symbol_t.get(varname)
                        .expect(format!("Variable {} is not defined!", varname).as_str()));
@@ -822,31 +2148,95 @@ impl BlockToIR {
                 }
 
                 self.alloc.free(ascii);
+
+                if let Some(col) = self.column {
+                    // a newline starts the count again
+                    let after = match s.rfind('\n') {
+                        Some(pos) => {
+                            to_r.push(BFQuad::Zero(col));
+                            &s[pos + 1..]
+                        },
+                        None => &s[..]
+                    };
+                    to_r.push(BFQuad::To(col));
+                    to_r.push(BFQuad::Constant(after.chars().count() as u32));
+                }
             },
 
-            DBExpr::E (ref expr) => {
+            DBExpr::E (ref expr) if self.running.is_some() => {
+                // computing the value might stop the program, and
+                // then the value should not be printed
                 let (el, ec) = self.ir_for_expr(expr);
                 to_r.extend(ec);
 
-                let tmp = self.alloc.reserve_range(15);
+                let running = self.running.unwrap();
+                let guard = self.alloc.reserve();
+                let tmp = self.alloc.reserve();
+                to_r.push(BFQuad::Zero(guard));
+                to_r.push(BFQuad::Zero(tmp));
+                to_r.push(BFQuad::AddTo(running, guard, tmp));
+                self.alloc.free(tmp);
 
-                for i in tmp..tmp+15 {
-                    to_r.push(BFQuad::Zero(i));
-                }
-                
-                to_r.push(BFQuad::Move(el, tmp));
+                let num = self.alloc.reserve();
+                to_r.push(BFQuad::Zero(num));
+                to_r.push(BFQuad::Move(el, num));
                 self.alloc.free(el);
 
-                to_r.push(BFQuad::To(tmp));
+                to_r.push(BFQuad::If(guard));
+                to_r.extend(self.ir_for_print_num(num));
+                to_r.push(BFQuad::EndIf(guard));
+                self.alloc.free(guard);
+            },
 
-                to_r.push(BFQuad::RawBF("[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<"));
-                
-                for i in tmp..tmp+15 {
-                    self.alloc.free(i);
-                }
+            DBExpr::E (ref expr) => {
+                let (el, ec) = self.ir_for_expr(expr);
+                to_r.extend(ec);
+                to_r.extend(self.ir_for_print_num(el));
+            }
+
+        }
+
+        return to_r;
+    }
+
+    // prints the number in el, consuming it
+    fn ir_for_print_num(&mut self, el: u32) -> Vec<BFQuad> {
+        let mut to_r = Vec::new();
+        if let Some(col) = self.column {
+            let tmp = self.alloc.reserve_range(20);
+            to_r.push(BFQuad::PrintNumCol(el, self.options.decimals, col, tmp));
+            for i in tmp..tmp+20 {
+                self.alloc.free(i);
+            }
+            self.alloc.free(el);
+            return to_r;
+        }
 
+        if self.options.decimals > 0 {
+            let tmp = self.alloc.reserve_range(20);
+            to_r.push(BFQuad::PrintNum(el, self.options.decimals, tmp));
+            for i in tmp..tmp+20 {
+                self.alloc.free(i);
             }
+            self.alloc.free(el);
+            return to_r;
+        }
+
+        let tmp = self.alloc.reserve_range(15);
 
+        for i in tmp..tmp+15 {
+            to_r.push(BFQuad::Zero(i));
+        }
+        
+        to_r.push(BFQuad::Move(el, tmp));
+        self.alloc.free(el);
+
+        to_r.push(BFQuad::To(tmp));
+
+        to_r.push(BFQuad::RawBF("[>>+>+<<<-]>>>[<<<+>>>-]<<+>[<->[>++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]++++++++[<++++++>-]>[<<+>>-]>[<<+>>-]<<]>]<[->>++++++++[<++++++>-]]<[.[-]<]<"));
+        
+        for i in tmp..tmp+15 {
+            self.alloc.free(i);
         }
 
         return to_r;
@@ -854,6 +2244,129 @@ impl BlockToIR {
 
 }
 
+// string literals are parsed on their own, but string
+// expressions treat them like any other term.
+fn to_string_expr(expr: &DBExpr) -> Expr {
+    return match *expr {
+        DBExpr::S(ref txt) => Expr::S(txt.clone()),
+        DBExpr::E(ref e) => e.clone()
+    };
+}
+
+// checks the arguments of a builtin function. Each entry of
+// is_string is true if that argument must be a string.
+// a piece of a PRINT USING format
+enum UsingPart {
+    Text(String),
+    // the digits before the point, how many of the last of them are
+    // padded with zeros, and the decimal places
+    Field(u32, u32, u32)
+}
+
+// splits a PRINT USING format into fields and the text between
+// them. A field is a run of # and 0, for digits padded with spaces
+// and zeros, with an optional point and more of them after it.
+fn parse_using(format: &str) -> Vec<UsingPart> {
+    let chars: Vec<char> = format.chars().collect();
+    let is_digit = |c: Option<&char>| c == Some(&'#') || c == Some(&'0');
+
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let starts_field = is_digit(chars.get(i))
+            || (chars[i] == '.' && is_digit(chars.get(i + 1)));
+        if !starts_field {
+            text.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        if !text.is_empty() {
+            parts.push(UsingPart::Text(mem::take(&mut text)));
+        }
+
+        let start = i;
+        let mut zeros = 0;
+        while is_digit(chars.get(i)) {
+            if chars[i] == '0' || zeros > 0 {
+                zeros += 1;
+            }
+            i += 1;
+        }
+        let width = (i - start) as u32;
+
+        let mut places = 0;
+        if chars.get(i) == Some(&'.') && is_digit(chars.get(i + 1)) {
+            i += 1;
+            while is_digit(chars.get(i)) {
+                places += 1;
+                i += 1;
+            }
+        }
+
+        // a cell holds at most 10 digits
+        if places > 9 {
+            panic!("PRINT USING format {:?} has more than 9 decimal places",
+                   format);
+        }
+        parts.push(UsingPart::Field(width, zeros, places));
+    }
+
+    if !text.is_empty() {
+        parts.push(UsingPart::Text(text));
+    }
+    return parts;
+}
+
+fn check_args(funcname: &str, args: &[Expr], is_string: &[bool]) {
+    if args.len() != is_string.len() {
+        panic!("{} expects {} arguments, but got {}!",
+               funcname, is_string.len(), args.len());
+    }
+
+    for (arg, &want_string) in args.iter().zip(is_string.iter()) {
+        if arg.is_string() != want_string {
+            panic!("Type mismatch in arguments to {}!", funcname);
+        }
+    }
+}
+
+fn mentions_var(expr: &Expr, varname: &str) -> bool {
+    return match *expr {
+        Expr::O(ref e1, _, ref e2) => mentions_var(e1, varname)
+            || mentions_var(e2, varname),
+        Expr::V(ref name) => name == varname,
+        Expr::E(ref e) => mentions_var(e, varname),
+        Expr::F(_, ref args) => args.iter().any(|e| mentions_var(e, varname)),
+        _ => false
+    };
+}
+
+// a copy of expr with every use of the variable varname replaced by
+// value, in parentheses
+fn replace_var(expr: &Expr, varname: &str, value: &Expr) -> Expr {
+    return match *expr {
+        Expr::V(ref name) if name == varname => Expr::E(Box::new(value.clone())),
+        Expr::O(ref e1, ref op, ref e2) => {
+            Expr::O(Box::new(replace_var(e1, varname, value)), op.clone(),
+                    Box::new(replace_var(e2, varname, value)))
+        },
+        Expr::A(ref def) => {
+            Expr::A(DBArrayDef {
+                varname: def.varname.clone(),
+                dims: def.dims.iter().map(|d| replace_var(d, varname, value)).collect()
+            })
+        },
+        Expr::E(ref e) => Expr::E(Box::new(replace_var(e, varname, value))),
+        Expr::F(ref name, ref args) => {
+            Expr::F(name.clone(),
+                    args.iter().map(|a| replace_var(a, varname, value)).collect())
+        },
+        _ => expr.clone()
+    };
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -875,10 +2388,11 @@ mod test {
         let parse = parser::parse_bytes(test_program.as_bytes());
         let res = blockgen::to_blocks(parse);
 
-        let mut ir_gen = BlockToIR::new(res, false);
+        let mut ir_gen = BlockToIR::new(res, false, Options::default());
         let ir = ir_gen.generate_ir();
         println!("{:?}", ir);
     }
 
 }
     
+
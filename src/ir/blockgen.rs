@@ -17,33 +17,51 @@
 // along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
 // 
 // < end copyright > 
-use parser::structs::{DBCommand,DBStmt};
+use parser::structs::{DBCommand,DBStmt,DBData};
 use std::collections::HashSet;
+use serde::{Serialize, Serializer};
 use std::collections::HashMap;
+use std::mem;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum SpecialOut {
     Next(usize),
     Return(usize),
     None
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Block {
+    #[serde(skip)]
     root: bool,
+    // the block to continue to when this block does not fall
+    // through to the next one (e.g. the end of a THEN branch
+    // of a single-line IF). For an IF, this is the false branch.
+    #[serde(skip)]
+    follow: Option<usize>,
     pub in_blocks: Vec<usize>,
     pub out_blocks: Vec<usize>,
     pub special_out: SpecialOut,
     pub cmds: Vec<DBCommand>,
     pub is_loop: bool,
     pub loop_exits: Vec<usize>,
+    #[serde(serialize_with = "sorted")]
     pub loop_nodes: HashSet<usize>,
 }
 
+// sets are written in order, so the output doesn't change between
+// runs
+fn sorted<S: Serializer>(set: &HashSet<usize>, s: S) -> Result<S::Ok, S::Error> {
+    let mut items: Vec<&usize> = set.iter().collect();
+    items.sort();
+    return items.serialize(s);
+}
+
 impl Block {
     fn new() -> Block {
         return Block {
             root: false,
+            follow: None,
             in_blocks: Vec::new(),
             out_blocks: Vec::new(),
             special_out: SpecialOut::None,
@@ -57,6 +75,7 @@ impl Block {
     fn new_root() -> Block {
         return Block {
             root: true,
+            follow: None,
             in_blocks: Vec::new(),
             out_blocks: Vec::new(),
             special_out: SpecialOut::None,
@@ -70,6 +89,7 @@ impl Block {
     pub fn new_loop(exits: Vec<usize>, nodes: HashSet<usize>) -> Block {
         return Block {
             root:false,
+            follow: None,
             in_blocks: Vec::new(),
             out_blocks: Vec::new(),
             special_out: SpecialOut::None,
@@ -105,32 +125,57 @@ enum BlockSplitType {
     NoBreak, BreakAfter, BreakBefore, BreakBoth
 }
 
+fn add_goto_targets(stmt: &DBStmt, goto_targets: &mut HashSet<u32>) {
+    match *stmt {
+        DBStmt::GOSUB { lineno }
+        | DBStmt::GOTO { lineno } => {
+            goto_targets.insert(lineno);
+        },
+
+        DBStmt::ONGOTO { ref linenos, .. }
+        | DBStmt::ONGOSUB { ref linenos, .. } => {
+            goto_targets.extend(linenos.iter());
+        },
+
+        DBStmt::IF { ref then_stmts, ref else_stmts, .. } => {
+            for s in then_stmts.iter().chain(else_stmts.iter()) {
+                add_goto_targets(s, goto_targets);
+            }
+        },
+        
+        _ => { }
+    };
+}
+
 pub fn get_goto_targets(stmts: &[DBCommand]) -> HashSet<u32> {
     let mut goto_targets = HashSet::new();
     for cmd in stmts.iter() {
-        match cmd.cmd {
-            DBStmt::GOSUB { lineno }
-            | DBStmt::IF { lineno, .. }
-            | DBStmt::GOTO { lineno } => {
-                goto_targets.insert(lineno);
-            },
-            
-            _ => { }
-        };
+        add_goto_targets(&cmd.cmd, &mut goto_targets);
     }
 
     return goto_targets;
 }
 
+// if a branch of an IF statement is just a jump (like THEN 27),
+// returns the line it jumps to.
+pub fn get_branch_jump(stmts: &[DBStmt]) -> Option<u32> {
+    if stmts.len() != 1 {
+        return None;
+    }
+
+    return match stmts[0] {
+        DBStmt::GOTO { lineno } => Some(lineno),
+        _ => None
+    };
+}
+
 fn get_all_program_data(stmts: &[DBCommand])
-                        -> Vec<u32>
+                        -> Vec<DBData>
 {
     let mut to_r = Vec::new();
     for cmd in stmts.iter() {
         if let DBStmt::DATA { seq: ref data } = cmd.cmd {
-            for flt in data.iter() {
-                to_r.push(*flt as u32);
-            }
+            to_r.extend(data.iter().cloned());
         }
     }
 
@@ -140,32 +185,34 @@ fn get_all_program_data(stmts: &[DBCommand])
 pub fn to_blocks(stmts: Vec<DBCommand>)
                  -> Vec<Block>
 {
+    let (blocks, implicit_end) = build_blocks(stmts);
+    ensure_no_dead_code(&blocks, implicit_end);
+    return blocks;
+}
+
+// splits a program into linked blocks, which can include blocks that
+// are never reached. Also returns the index of the END block added
+// when the program doesn't end with one.
+pub fn build_blocks(stmts: Vec<DBCommand>)
+                    -> (Vec<Block>, Option<usize>)
+{
 
-    let mut all_stmts = stmts;
+    let all_stmts = stmts;
+
+    // a program that runs off its last line stops there, as if
+    // it ended with an END
+    let last_ln = all_stmts.last().map(|c| c.ln);
+    let needs_end = match all_stmts.last() {
+        Some(cmd) => !cmd.cmd.is_end(),
+        None => true
+    };
     // first, we need to resolve all the READ and DATA commands.
     // to do this, we first collect a vector of all the DATA items.
-    // Then, we associate each READ command with the appropiate data
+    // Then, once the program is split into blocks, we associate
+    // each READ command with the appropiate data
     let mut data = get_all_program_data(&all_stmts);
     data.reverse();
 
-    for cmd in all_stmts.iter_mut() {
-        let num_data_items = match cmd.cmd {
-            DBStmt::READ { varnames: ref x } => {
-                x.len()
-            },
-
-            _ => {0}
-        };
-        let ln = cmd.ln;
-        for _ in 0..num_data_items {
-            cmd.add_data(data.pop()
-                         .expect(
-                             format!("Not enough DATA for READ on line {}",
-                                     ln).as_str()));
-        }
-        
-    }
-    
     // iterate through each command, splitting them
     // into blocks. To do this, we will first build
     // a vector of every GOTO target. This will be used
@@ -178,28 +225,105 @@ pub fn to_blocks(stmts: Vec<DBCommand>)
 
     let mut blocks = Vec::new();
     let mut curr_block = Block::new_root();
+    let mut dangling = Vec::new();
+
+    split_blocks(all_stmts, &goto_targets,
+                 &mut blocks, &mut curr_block, &mut dangling);
 
-    for cmd in all_stmts {
+    // the added END gets a block of its own, so that it can be
+    // left alone if nothing reaches it
+    let implicit_end = if needs_end {
+        if !curr_block.cmds.is_empty() {
+            let prev = mem::replace(&mut curr_block, Block::new());
+            push_block(&mut blocks, prev, &mut dangling);
+        }
+
+        let mut end = Block::new();
+        end.cmds.push(DBCommand { ln: last_ln.unwrap_or(0),
+                                  cmd: DBStmt::END,
+                                  data: Vec::new() });
+        push_block(&mut blocks, end, &mut dangling);
+        Some(blocks.len() - 1)
+    } else {
+        None
+    };
+
+    if !curr_block.cmds.is_empty() || !dangling.is_empty() {
+        panic!("Last block was not empty: {:?}", curr_block);
+    }
+
+    // blocks are in program order, so READ commands inside
+    // of IF statements get their data in the right order too.
+    for block in blocks.iter_mut() {
+        for cmd in block.cmds.iter_mut() {
+            let num_data_items = match cmd.cmd {
+                DBStmt::READ { varnames: ref x } => {
+                    x.len()
+                },
+
+                _ => {0}
+            };
+            let ln = cmd.ln;
+            for _ in 0..num_data_items {
+                cmd.add_data(data.pop()
+                             .expect(
+                                 format!("Not enough DATA for READ on line {}",
+                                         ln).as_str()));
+            }
+        }
+    }
+    
+    link_blocks(&mut blocks);
+
+    return (blocks, implicit_end);
+}
+
+// pushes a new block. Any dangling blocks (the ends of the branches
+// of a single-line IF) continue to this block.
+fn push_block(blocks: &mut Vec<Block>, block: Block,
+              dangling: &mut Vec<usize>) {
+    for idx in dangling.drain(..) {
+        blocks[idx].follow = Some(blocks.len());
+    }
+    blocks.push(block);
+}
+
+fn split_blocks(cmds: Vec<DBCommand>,
+                goto_targets: &HashSet<u32>,
+                blocks: &mut Vec<Block>,
+                curr_block: &mut Block,
+                dangling: &mut Vec<usize>) {
+    let mut prev_ln = None;
+    for cmd in cmds {
         let mut new_block = match cmd.cmd {
             DBStmt::END
+                | DBStmt::STOP
                 | DBStmt::RETURN
                 | DBStmt::NEXT { .. }
+                | DBStmt::WEND
+                | DBStmt::LOOP { .. }
             | DBStmt::GOSUB { .. }
             | DBStmt::GOTO { .. }
+            | DBStmt::ONGOTO { .. }
+            | DBStmt::ONGOSUB { .. }
             | DBStmt::IF { .. } => {
                 BlockSplitType::BreakAfter
             }
             
 
             
-            DBStmt::FOR {..} => {
+            DBStmt::FOR {..} | DBStmt::WHILE {..} | DBStmt::DO => {
                 BlockSplitType::BreakBoth
             }
 
             _ => BlockSplitType::NoBreak
         };
 
-        if goto_targets.contains(&cmd.ln) {
+        // only the first statement on a line can be jumped to.
+        let first_on_line = prev_ln != Some(cmd.ln);
+        prev_ln = Some(cmd.ln);
+
+        if first_on_line && goto_targets.contains(&cmd.ln) {
             new_block = match new_block {
                 BlockSplitType::NoBreak | BlockSplitType::BreakBefore =>
                     BlockSplitType::BreakBefore,
@@ -207,31 +331,41 @@ pub fn to_blocks(stmts: Vec<DBCommand>)
                     BlockSplitType::BreakBoth
             };
         }
+
+        // the statements inside of a single-line IF become
+        // their own blocks, right after the IF.
+        let branches = match cmd.cmd {
+            DBStmt::IF { ref then_stmts, ref else_stmts, .. } => {
+                Some((then_stmts.clone(), else_stmts.clone()))
+            },
+            _ => None
+        };
+        let ln = cmd.ln;
         
         match new_block {
             BlockSplitType::BreakBefore => {
                 if !curr_block.cmds.is_empty() {
-                    blocks.push(curr_block);
-                    curr_block = Block::new();
+                    let prev = mem::replace(curr_block, Block::new());
+                    push_block(blocks, prev, dangling);
                 }
                 curr_block.cmds.push(cmd);
             }
             
             BlockSplitType::BreakAfter => {
                 curr_block.cmds.push(cmd);
-                blocks.push(curr_block);
-                curr_block = Block::new();
+                let prev = mem::replace(curr_block, Block::new());
+                push_block(blocks, prev, dangling);
             },
 
             BlockSplitType::BreakBoth => {
                 if !curr_block.cmds.is_empty() {
-                    blocks.push(curr_block);
-                    curr_block = Block::new();
+                    let prev = mem::replace(curr_block, Block::new());
+                    push_block(blocks, prev, dangling);
                 }
 
                 curr_block.cmds.push(cmd);
-                blocks.push(curr_block);
-                curr_block = Block::new();
+                let prev = mem::replace(curr_block, Block::new());
+                push_block(blocks, prev, dangling);
             },
 
             BlockSplitType::NoBreak => {
@@ -239,18 +373,50 @@ pub fn to_blocks(stmts: Vec<DBCommand>)
             }
         }
 
+        if let Some((then_stmts, else_stmts)) = branches {
+            let if_idx = blocks.len() - 1;
+
+            let then_has_blocks = get_branch_jump(&then_stmts).is_none();
+            if then_has_blocks {
+                let ends = split_branch(then_stmts, ln, goto_targets, blocks);
+                dangling.extend(ends);
+            }
+
+            if else_stmts.is_empty() {
+                // when false, the IF skips over the THEN blocks
+                // to the next line.
+                if then_has_blocks {
+                    dangling.push(if_idx);
+                }
+            } else if get_branch_jump(&else_stmts).is_none() {
+                blocks[if_idx].follow = Some(blocks.len());
+                let ends = split_branch(else_stmts, ln, goto_targets, blocks);
+                dangling.extend(ends);
+            }
+        }
     }
+}
+
+// splits the statements of one branch of a single-line IF into
+// blocks. Returns the blocks that need to continue to whatever
+// comes after the IF.
+fn split_branch(stmts: Vec<DBStmt>, ln: u32,
+                goto_targets: &HashSet<u32>,
+                blocks: &mut Vec<Block>) -> Vec<usize> {
+    let cmds = stmts.into_iter()
+        .map(|stmt| DBCommand { ln: ln, cmd: stmt, data: Vec::new() })
+        .collect();
+
+    let mut curr_block = Block::new();
+    let mut dangling = Vec::new();
+    split_blocks(cmds, goto_targets, blocks, &mut curr_block, &mut dangling);
 
     if !curr_block.cmds.is_empty() {
-        panic!("Last block was not empty -- does the \
-                program end with an END statment? Last block was: {:?}",
-               curr_block);
+        push_block(blocks, curr_block, &mut dangling);
     }
-    
-    link_blocks(&mut blocks);
 
-    ensure_no_dead_code(&blocks);
-    return blocks;
+    dangling.push(blocks.len() - 1);
+    return dangling;
 }
 
 enum FollowType {
@@ -268,17 +434,37 @@ macro_rules! get_last_cmd {
     }
 }
 
-macro_rules! get_first_cmd {
-    ($blk:expr) => {
-        {
-            $blk.cmds.iter().take(1).last()
-                .expect("Block did not have any commands!")
-                .clone()
+
+// the block that control falls through to after block idx
+fn next_block(blocks: &[Block], idx: usize) -> usize {
+    return blocks[idx].follow.unwrap_or(idx + 1);
+}
+
+// scans forward from the WHILE or DO at block idx to the WEND or
+// LOOP that closes it, skipping over nested loops of the same kind.
+fn find_loop_end(blocks: &[Block], idx: usize) -> usize {
+    let is_while = matches!(get_last_cmd!(blocks[idx]).cmd,
+                            DBStmt::WHILE { .. });
+
+    let mut depth = 0;
+    for (j, block) in blocks.iter().enumerate().skip(idx + 1) {
+        let delta = match (&get_last_cmd!(block).cmd, is_while) {
+            (&DBStmt::WHILE { .. }, true) | (&DBStmt::DO, false) => 1,
+            (&DBStmt::WEND, true) | (&DBStmt::LOOP { .. }, false) => -1,
+            _ => 0
+        };
+
+        if delta < 0 && depth == 0 {
+            return j;
         }
-        
+        depth += delta;
     }
-}
 
+    if is_while {
+        panic!("No matching WEND statement for WHILE statement!");
+    }
+    panic!("No matching LOOP statement for DO statement!");
+}
 
 pub fn link_blocks (blocks: &mut Vec<Block>) {
     // first, build a map from line no to block.
@@ -288,7 +474,9 @@ pub fn link_blocks (blocks: &mut Vec<Block>) {
         let block_cell = block;
         let cmds_iter = block_cell.cmds.iter();
         for stmt in cmds_iter {
-            lno_map.insert(stmt.ln, idx);
+            // a line can span several blocks (e.g. a single-line IF),
+            // jumps go to the first one.
+            lno_map.entry(stmt.ln).or_insert(idx);
         }
     }
 
@@ -298,6 +486,7 @@ pub fn link_blocks (blocks: &mut Vec<Block>) {
         
         // forward link each block
         let last = get_last_cmd!(blocks[i]);
+        let next = next_block(blocks, i);
 
         let nl = match last.cmd {
             DBStmt::FOR { ref varname, .. } => {
@@ -337,89 +526,64 @@ pub fn link_blocks (blocks: &mut Vec<Block>) {
                 FollowType::NextLineDoesNotFollow
             }
 
-            DBStmt::GOSUB { ref lineno } => {
-                // add the approp lineno to our outlist
-                // then, add the line after us to our outlist
-
-                let out_block = *lno_map.get(lineno)
-                    .expect("Could not find matching lineno for GOSUB");
-
-
-                // check to make sure there is a return...
-                let mut found_matching = false;
-                let mut subroutine_start = 0;
-                let mut subroutine_return = 0;
-                
-                for j in 0..blocks.len() {
-                    if j == i {
-                        continue
-                    }
-
-                    let last = get_last_cmd!(blocks[j]);
-
-                    if last.ln < *lineno {
-                        continue;
-                    }
-
-                    if let DBStmt::RETURN = last.cmd {
-                        if !blocks[j].out_blocks.is_empty() {
-                            // TODO copy all the blocks from out_block
-                            // to j. Add them to the end of the block
-                            // list. Link to those.
-                            let mut copy = Vec::new();
-                            
-                            
-                            // Clippy suggests using an iterator,
-                            // but this seems much cleaner than
-                            // the alternative:
-                            // blocks.iter().take(j+1).skip(out_block)
-                            #[cfg_attr(feature = "cargo-clippy",
-                                       allow(needless_range_loop))]
-                            for i in out_block..j+1 {
-                                copy.push(blocks[i].clone());
-                            }
-                            
-
+            DBStmt::WHILE { .. } | DBStmt::DO => {
+                // like FOR, the loop end gets a special edge back to
+                // us, so the loop never shows up as a goto cycle.
+                let j = find_loop_end(blocks, i);
+                let after = next_block(blocks, j);
+                if after >= blocks.len() {
+                    panic!("Program does not end with an END statement!");
+                }
+                blocks[j].special_out = SpecialOut::Next(i);
+                blocks[i].add_in_block(j);
+                blocks[i].add_out_block(after);
+                blocks[after].add_in_block(i);
+                FollowType::NextLineFollows
+            },
 
-                            let num_copied = copy.len();
-                            let curr_blocks = blocks.len();
-                            copy[0].clear_in_blocks();
-                            copy[num_copied-1].clear_out_blocks();
-                            
-                            blocks.extend(copy);
-                            
-                            subroutine_start = curr_blocks;
-                            subroutine_return = blocks.len()-1;
-                            found_matching = true;
-                            break;
-                        } else {
-                            subroutine_start = out_block;
-                            subroutine_return = j;
-                            found_matching = true;
-                            break;
-                        }
+            DBStmt::WEND | DBStmt::LOOP { .. } => {
+                match blocks[i].special_out {
+                    SpecialOut::Next (..) => {},
+                    _ => {
+                        panic!("{} statement without preceeding loop!",
+                               last.cmd.get_string_type());
                     }
-                
-
-                }
-                                
-                if !found_matching {
-                    panic!("Could not find a RETURN for GOSUB");
                 }
 
-                blocks[subroutine_return].add_out_block(i+1);
-                blocks[i+1].add_in_block(subroutine_return);
+                FollowType::NextLineDoesNotFollow
+            }
+
+            DBStmt::GOSUB { ref lineno } => {
+                // add the approp lineno to our outlist
+                // then, add the line after us to our outlist
+                let subroutine_start = link_subroutine(blocks, &lno_map,
+                                                       i, *lineno);
 
                 blocks[subroutine_start].add_in_block(i);
                 blocks[i].add_out_block(subroutine_start);
                 
-                blocks[i].special_out = SpecialOut::Return(i+1);
-                blocks[i+1].add_in_block(i);
+                blocks[i].special_out = SpecialOut::Return(next);
+                blocks[next].add_in_block(i);
             
                 
                 FollowType::NextLineDoesNotFollow
             },
 
+            DBStmt::ONGOSUB { ref linenos, .. } => {
+                // each target is a subroutine that returns to the
+                // line after us. The line after us is also an
+                // output, since out-of-range values fall through.
+                for lineno in linenos.iter() {
+                    let subroutine_start = link_subroutine(blocks, &lno_map,
+                                                           i, *lineno);
+                    blocks[subroutine_start].add_in_block(i);
+                    blocks[i].add_out_block(subroutine_start);
+                }
+
+                blocks[i].special_out = SpecialOut::Return(next);
+                FollowType::NextLineFollows
+            },
+
             DBStmt::GOTO { ref lineno } => {
                 let out_block = *lno_map.get(lineno)
                     .expect("Could not find line used by GOTO");
@@ -431,38 +595,224 @@ pub fn link_blocks (blocks: &mut Vec<Block>) {
                 FollowType::NextLineDoesNotFollow
             },
 
-            DBStmt::IF { ref lineno, .. } => {
-                // add the true branch as an output
-                let out_block = *lno_map.get(lineno)
-                    .expect("Could not find line used by IF");
+            DBStmt::ONGOTO { ref linenos, .. } => {
+                // add each target as an output, in order, followed
+                // by the next line for out-of-range values.
+                for lineno in linenos.iter() {
+                    let out_block = *lno_map.get(lineno)
+                        .expect("Could not find line used by ON GOTO");
+
+                    blocks[out_block].add_in_block(i);
+                    blocks[i].add_out_block(out_block);
+                }
+
+                FollowType::NextLineFollows
+            },
+
+            DBStmt::IF { ref then_stmts, ref else_stmts, .. } => {
+                // add the true branch as an output. It is either a
+                // jump, or the THEN statements, which come right
+                // after the IF.
+                let out_block = match get_branch_jump(then_stmts) {
+                    Some(lineno) => *lno_map.get(&lineno)
+                        .expect("Could not find line used by IF"),
+                    None => i + 1
+                };
 
                 blocks[out_block].add_in_block(i);
                 blocks[i].add_out_block(out_block);
-                
-                FollowType::NextLineFollows
+
+                // the false branch is either a jump, or
+                // continues to the ELSE statements or next line.
+                match get_branch_jump(else_stmts) {
+                    Some(lineno) => {
+                        let out_block = *lno_map.get(&lineno)
+                            .expect("Could not find line used by ELSE");
+
+                        blocks[out_block].add_in_block(i);
+                        blocks[i].add_out_block(out_block);
+                        FollowType::NextLineDoesNotFollow
+                    },
+                    None => FollowType::NextLineFollows
+                }
             },
 
-            DBStmt::END | DBStmt::RETURN => FollowType::NextLineDoesNotFollow,
+            DBStmt::END | DBStmt::STOP | DBStmt::RETURN =>
+                FollowType::NextLineDoesNotFollow,
 
             _ => FollowType::NextLineFollows
         };
 
 
         if let FollowType::NextLineFollows = nl {
-            if i + 1 > blocks.len() {
+            if next >= blocks.len() {
                 panic!("Program does not end with an END statement!");
             }
             
-            blocks[i].add_out_block(i+1);
-            blocks[i+1].add_in_block(i);
+            blocks[i].add_out_block(next);
+            blocks[next].add_in_block(i);
         }
 
         i += 1;
     }
 }
 
-fn ensure_no_dead_code(blocks: &[Block]) {
-    for i in blocks.iter() {
+// finds the subroutine starting at lineno (copying it if it has
+// already been linked to another caller) and links its RETURN back
+// to the block after the caller. Returns the index of the first
+// block of the subroutine.
+fn link_subroutine(blocks: &mut Vec<Block>,
+                   lno_map: &HashMap<u32, usize>,
+                   caller: usize,
+                   lineno: u32) -> usize
+{
+    let out_block = *lno_map.get(&lineno)
+        .expect("Could not find matching lineno for GOSUB");
+
+
+    // check to make sure there is a return...
+    let mut found_matching = false;
+    let mut subroutine_start = 0;
+    let mut subroutine_return = 0;
+    
+    for j in 0..blocks.len() {
+        if j == caller {
+            continue
+        }
+
+        let last = get_last_cmd!(blocks[j]);
+
+        if last.ln < lineno {
+            continue;
+        }
+
+        if let DBStmt::RETURN = last.cmd {
+            if !blocks[j].out_blocks.is_empty() {
+                // TODO copy all the blocks from out_block
+                // to j. Add them to the end of the block
+                // list. Link to those.
+                let mut copy = Vec::new();
+                
+                
+                // Clippy suggests using an iterator,
+                // but this seems much cleaner than
+                // the alternative:
+                // blocks.iter().take(j+1).skip(out_block)
+                #[cfg_attr(feature = "cargo-clippy",
+                           allow(needless_range_loop))]
+                for i in out_block..j+1 {
+                    copy.push(blocks[i].clone());
+                }
+                
+
+
+                let num_copied = copy.len();
+                let curr_blocks = blocks.len();
+                copy[0].clear_in_blocks();
+                copy[num_copied-1].clear_out_blocks();
+                
+                blocks.extend(copy);
+                
+                subroutine_start = curr_blocks;
+                subroutine_return = blocks.len()-1;
+                found_matching = true;
+                break;
+            } else {
+                subroutine_start = out_block;
+                subroutine_return = j;
+                found_matching = true;
+                break;
+            }
+        }
+    
+
+    }
+                    
+    if !found_matching {
+        panic!("Could not find a RETURN for GOSUB");
+    }
+
+    let return_to = next_block(blocks, caller);
+    blocks[subroutine_return].add_out_block(return_to);
+    blocks[return_to].add_in_block(subroutine_return);
+
+    return subroutine_start;
+}
+
+// the blocks control can get to from the start of the program
+pub fn reachable(blocks: &[Block]) -> HashSet<usize> {
+    let mut reached = HashSet::new();
+    let mut stack = vec![0];
+    while let Some(v) = stack.pop() {
+        if !reached.insert(v) {
+            continue;
+        }
+
+        stack.extend(blocks[v].out_blocks.iter().cloned());
+        match blocks[v].special_out {
+            SpecialOut::Next(pos) | SpecialOut::Return(pos) => stack.push(pos),
+            SpecialOut::None => {}
+        };
+    }
+    return reached;
+}
+
+// removes the blocks that are never reached, and renumbers the
+// edges of the rest. The added END is kept, as is the end of a loop
+// whose start is reached, since the loop needs it.
+pub fn strip_dead_code(blocks: Vec<Block>, implicit_end: Option<usize>) -> Vec<Block> {
+    let mut keep = reachable(&blocks);
+    keep.extend(implicit_end);
+    for (idx, b) in blocks.iter().enumerate() {
+        if let SpecialOut::Next(start) = b.special_out {
+            if keep.contains(&start) {
+                keep.insert(idx);
+            }
+        }
+    }
+
+    if keep.len() == blocks.len() {
+        return blocks;
+    }
+
+    let mut new_idx = HashMap::new();
+    for idx in 0..blocks.len() {
+        if keep.contains(&idx) {
+            let next = new_idx.len();
+            new_idx.insert(idx, next);
+        }
+    }
+
+    let remap = |edges: &[usize]| -> Vec<usize> {
+        return edges.iter().filter_map(|e| new_idx.get(e).cloned()).collect();
+    };
+
+    let mut to_r = Vec::new();
+    for (idx, mut b) in blocks.into_iter().enumerate() {
+        if !keep.contains(&idx) {
+            continue;
+        }
+
+        b.in_blocks = remap(&b.in_blocks);
+        b.out_blocks = remap(&b.out_blocks);
+        b.follow = b.follow.and_then(|f| new_idx.get(&f).cloned());
+        b.special_out = match b.special_out {
+            SpecialOut::Next(pos) => SpecialOut::Next(new_idx[&pos]),
+            SpecialOut::Return(pos) => SpecialOut::Return(new_idx[&pos]),
+            SpecialOut::None => SpecialOut::None
+        };
+        to_r.push(b);
+    }
+
+    return to_r;
+}
+
+fn ensure_no_dead_code(blocks: &[Block], implicit_end: Option<usize>) {
+    for (idx, i) in blocks.iter().enumerate() {
+        if Some(idx) == implicit_end {
+            continue;
+        }
+
         if !i.root && i.in_blocks.is_empty() {
             let last = get_last_cmd!(i);
             panic!("Dead code in block ending at {}", last.ln);
@@ -520,5 +870,80 @@ mod test {
         
     }
 
+    #[test]
+    fn single_line_if() {
+        let test_program = "\
+10 LET X = 5
+20 IF X > 2 THEN PRINT \"a\" ELSE PRINT \"b\"
+30 END\n";
+
+        let parse = parser::parse_bytes(test_program.as_bytes());
+        let blocks = to_blocks(parse);
+
+        // the IF, its THEN and ELSE branches, and the END
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(blocks[0].out_blocks, vec![1, 2]);
+        assert_eq!(blocks[1].out_blocks, vec![3]);
+        assert_eq!(blocks[2].out_blocks, vec![3]);
+    }
+
+    #[test]
+    fn implicit_end() {
+        // running off the last line ends the program
+        let test_program = "\
+10 LET X = 5
+20 IF X > 2 THEN PRINT \"a\"\n";
+
+        let parse = parser::parse_bytes(test_program.as_bytes());
+        let blocks = to_blocks(parse);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].out_blocks, vec![1, 2]);
+        assert_eq!(blocks[1].out_blocks, vec![2]);
+
+        // nothing reaches the added END after an endless loop
+        let test_program = "\
+10 PRINT \"a\"
+20 GOTO 10\n";
+
+        let parse = parser::parse_bytes(test_program.as_bytes());
+        to_blocks(parse);
+    }
+
+    #[test]
+    fn strip_dead_code_test() {
+        let test_program = "\
+10 PRINT \"test\"
+15 GOTO 35
+20 LET X = 40 * 3
+30 PRINT X
+35 FOR i = 0 TO 40
+40 PRINT i
+50 NEXT i
+70 END\n";
+
+        let parse = parser::parse_bytes(test_program.as_bytes());
+        let (blocks, implicit_end) = build_blocks(parse);
+        let blocks = strip_dead_code(blocks, implicit_end);
+        ensure_no_dead_code(&blocks, None);
+
+        let lines: Vec<u32> = blocks.iter()
+            .flat_map(|b| b.cmds.iter().map(|c| c.ln))
+            .collect();
+        assert_eq!(lines, vec![10, 15, 35, 40, 50, 70]);
+        assert_eq!(blocks[0].out_blocks, vec![1]);
+        assert_eq!(blocks[1].in_blocks, vec![0, 2]);
+
+        // the NEXT of a loop that is always left with a GOTO is kept
+        let test_program = "\
+10 FOR I = 1 TO 3
+20 GOTO 40
+30 NEXT I
+40 END\n";
+
+        let parse = parser::parse_bytes(test_program.as_bytes());
+        let (blocks, implicit_end) = build_blocks(parse);
+        let count = blocks.len();
+        assert_eq!(strip_dead_code(blocks, implicit_end).len(), count);
+    }
+
 }
-    
@@ -17,7 +17,7 @@
 // along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
 // 
 // < end copyright > 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use ir::blockgen::{Block};
 
 
@@ -242,6 +242,8 @@ fn get_nodes_for_back_edge(stmts: &[Block],
     return loop_nodes;
 }
 
+// the blocks outside the loop that it goes to, in block order so
+// that a program always compiles the same way
 fn collect_loop_exits(stmts: &[Block],
                       loop_nodes: &HashSet<usize>)
                       -> Vec<usize>
@@ -259,6 +261,7 @@ fn collect_loop_exits(stmts: &[Block],
         }
     }
 
+    to_r.sort();
     return to_r;
 }
                          
@@ -277,10 +280,21 @@ pub fn eliminate_gotos(stmts: &mut Vec<Block>) {
                      &mut colors, 0);
 
 
+    // several back edges can share a header (e.g. a state machine
+    // where each state GOTOs back to the dispatch line). Those form
+    // a single loop, whose nodes are the union of each edge's nodes.
+    let mut headers = BTreeMap::new();
     for ed in back_edges.iter() {
-        let loop_nodes = get_nodes_for_back_edge(stmts, ed);
-        let exit_nodes = collect_loop_exits(stmts, &loop_nodes);
         let (_end, header) = *ed;
+        headers.entry(header).or_insert_with(Vec::new).push(*ed);
+    }
+
+    for (header, edges) in headers {
+        let mut loop_nodes = HashSet::new();
+        for ed in edges.iter() {
+            loop_nodes.extend(get_nodes_for_back_edge(stmts, ed));
+        }
+        let exit_nodes = collect_loop_exits(stmts, &loop_nodes);
 
         let mut loop_block = Block::new_loop(exit_nodes, loop_nodes);
         let lp_idx = stmts.len();
@@ -294,8 +308,9 @@ pub fn eliminate_gotos(stmts: &mut Vec<Block>) {
                 .expect("Incoming and outgoing edges not set correctly!");
 
 
-            stmts[incoming].out_blocks.remove(idx);
-            stmts[incoming].out_blocks.push(lp_idx);
+            // replace the edge in place: branching blocks (IF, ON)
+            // rely on the order of their out blocks.
+            stmts[incoming].out_blocks[idx] = lp_idx;
 
         }
 
@@ -0,0 +1,187 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use ir::blockgen::{Block, SpecialOut};
+use ir::goto_elim::build_dominated_sets;
+
+// the control flow of the blocks. Each loop's body is drawn as a
+// cluster around its nodes, with the loop block as its entry.
+pub fn to_graphviz(blocks: &[Block]) -> String {
+    let mut w = Vec::new();
+
+    writeln!(&mut w, "digraph G {{").unwrap();
+    writeln!(&mut w, "node [shape=box];").unwrap();
+    write_nodes(&mut w, blocks);
+
+    // a node is drawn in the smallest loop holding it, and each
+    // loop inside the smallest loop holding all of its nodes
+    let loops: Vec<usize> = (0..blocks.len())
+        .filter(|&idx| blocks[idx].is_loop)
+        .collect();
+    let mut owner = HashMap::new();
+    let mut parent = HashMap::new();
+    for &lp in loops.iter() {
+        owner.insert(lp, lp);
+    }
+    for &lp in loops.iter() {
+        let nodes = &blocks[lp].loop_nodes;
+        for &node in nodes.iter() {
+            if blocks[node].is_loop {
+                continue;
+            }
+            let smaller = match owner.get(&node) {
+                Some(&other) => nodes.len() < blocks[other].loop_nodes.len(),
+                None => true
+            };
+            if smaller {
+                owner.insert(node, lp);
+            }
+        }
+
+        let around = loops.iter()
+            .filter(|&&other| nodes.len() < blocks[other].loop_nodes.len()
+                    && nodes.is_subset(&blocks[other].loop_nodes))
+            .min_by_key(|&&other| blocks[other].loop_nodes.len());
+        if let Some(&other) = around {
+            parent.insert(lp, other);
+        }
+    }
+
+    for &lp in loops.iter() {
+        if !parent.contains_key(&lp) {
+            write_cluster(&mut w, lp, &loops, &owner, &parent);
+        }
+    }
+
+    for (idx, b) in blocks.iter().enumerate() {
+        for out_blk in b.out_blocks.iter() {
+            writeln!(&mut w, "{} -> {};", idx, out_blk).unwrap();
+        }
+
+        for exit in b.loop_exits.iter() {
+            writeln!(&mut w, "{} -> {} [style=dashed, label=\"exit\"];",
+                     idx, exit).unwrap();
+        }
+
+        match b.special_out {
+            SpecialOut::Next(pos) => {
+                writeln!(&mut w, "{} -> {} [style=dotted, label=\"next\"];",
+                         idx, pos).unwrap();
+            },
+
+            SpecialOut::Return(pos) => {
+                writeln!(&mut w, "{} -> {} [style=dotted, label=\"return\"];",
+                         idx, pos).unwrap();
+            },
+
+            SpecialOut::None => {}
+        };
+    }
+
+    writeln!(&mut w, "}}").unwrap();
+
+    return String::from_utf8(w).unwrap();
+}
+
+// the dominator tree of the blocks: each block hangs off its
+// immediate dominator, the closest block every path to it passes
+pub fn to_dominator_graphviz(blocks: &[Block]) -> String {
+    let dominated = build_dominated_sets(blocks);
+    let mut w = Vec::new();
+
+    writeln!(&mut w, "digraph G {{").unwrap();
+    writeln!(&mut w, "node [shape=box];").unwrap();
+    write_nodes(&mut w, blocks);
+
+    // blocks that can't be reached count as dominated by every
+    // block, so they are left out
+    let mut reached = HashSet::new();
+    let mut stack = vec![0];
+    while let Some(v) = stack.pop() {
+        if reached.insert(v) {
+            stack.extend(blocks[v].out_blocks.iter().cloned());
+        }
+    }
+
+    for idx in 1..blocks.len() {
+        if !reached.contains(&idx) {
+            continue;
+        }
+
+        let idom = (0..blocks.len())
+            .filter(|&d| d != idx && dominated[&d].contains(&idx))
+            .min_by_key(|d| dominated[d].len());
+
+        if let Some(idom) = idom {
+            writeln!(&mut w, "{} -> {};", idom, idx).unwrap();
+        }
+    }
+
+    writeln!(&mut w, "}}").unwrap();
+
+    return String::from_utf8(w).unwrap();
+}
+
+// labels every block with its statements, one per line
+fn write_nodes(w: &mut Vec<u8>, blocks: &[Block]) {
+    for (idx, b) in blocks.iter().enumerate() {
+        if b.is_loop {
+            writeln!(w, "{} [label=\"{}: loop\", shape=ellipse];",
+                     idx, idx).unwrap();
+            continue;
+        }
+
+        let mut label = format!("{}:\\l", idx);
+        for cmd in b.cmds.iter() {
+            let line = format!("{}", cmd)
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+            label.push_str(&line);
+            label.push_str("\\l");
+        }
+        writeln!(w, "{} [label=\"{}\"];", idx, label).unwrap();
+    }
+}
+
+fn write_cluster(w: &mut Vec<u8>, lp: usize, loops: &[usize],
+                 owner: &HashMap<usize, usize>,
+                 parent: &HashMap<usize, usize>) {
+    writeln!(w, "subgraph cluster_{} {{", lp).unwrap();
+    writeln!(w, "label=\"loop {}\";", lp).unwrap();
+    writeln!(w, "style=dashed;").unwrap();
+
+    let mut nodes: Vec<usize> = owner.iter()
+        .filter(|&(_, &o)| o == lp)
+        .map(|(&node, _)| node)
+        .collect();
+    nodes.sort();
+    for node in nodes {
+        writeln!(w, "{};", node).unwrap();
+    }
+
+    for &inner in loops.iter() {
+        if parent.get(&inner) == Some(&lp) {
+            write_cluster(w, inner, loops, owner, parent);
+        }
+    }
+
+    writeln!(w, "}}").unwrap();
+}
@@ -0,0 +1,253 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::{BTreeMap, HashMap};
+use ir::blockgen::Block;
+use parser::structs::{DBStmt, DBExpr, DBArrayDef, DBLetTarget};
+use parser::ast::Expr;
+
+// arrays used without a DIM get this size in each dimension, so
+// they can be indexed from 0 to 10
+pub const DEFAULT_ARRAY_SIZE: usize = 11;
+
+// the numeric variables and arrays of a program, in the order they
+// first appear, and its DEF functions with their variable and body
+pub struct Storage {
+    pub vars: Vec<String>,
+    pub arrays: Vec<(String, Vec<usize>)>,
+    pub functions: HashMap<String, (String, DBExpr)>
+}
+
+// the cells each variable, array and string variable was given
+#[derive(Debug, Serialize)]
+pub struct Layout {
+    pub variables: BTreeMap<String, u32>,
+    pub arrays: BTreeMap<String, ArrayLayout>,
+    pub strings: BTreeMap<String, u32>
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArrayLayout {
+    pub start: u32,
+    pub dims: Vec<usize>
+}
+
+pub fn find_storage(blocks: &[Block]) -> Storage {
+    let mut dims = HashMap::new();
+    let mut storage = Storage { vars: Vec::new(), arrays: Vec::new(),
+                                functions: HashMap::new() };
+    let mut used = Vec::new();
+
+    for block in blocks.iter() {
+        for cmd in block.cmds.iter() {
+            match cmd.cmd {
+                DBStmt::DIM { ref arrays } => {
+                    for array in arrays.iter() {
+                        if dims.contains_key(&array.varname) {
+                            panic!("Array {} is dimensioned twice", array.varname);
+                        }
+                        dims.insert(array.varname.clone(), get_dim_sizes(array));
+                        add_array(&mut used, &array.varname, array.dims.len());
+                    }
+                },
+
+                DBStmt::DEF { ref funcname, ref varname, ref expr } => {
+                    if storage.functions.contains_key(funcname) {
+                        panic!("Function {} is defined twice", funcname);
+                    }
+                    storage.functions.insert(funcname.clone(),
+                                             (varname.clone(), expr.clone()));
+                },
+
+                _ => {}
+            }
+
+            visit_stmt(&cmd.cmd, &mut storage.vars, &mut used);
+        }
+    }
+
+    // calls to DEF functions look like arrays, but aren't
+    used.retain(|(name, _)| !storage.functions.contains_key(name));
+
+    for (name, num_dims) in used {
+        let sizes = dims.remove(&name)
+            .unwrap_or_else(|| vec![DEFAULT_ARRAY_SIZE; num_dims]);
+        storage.arrays.push((name, sizes));
+    }
+
+    return storage;
+}
+
+// adds the arrays a statement indexes, including in the branches
+// of an IF, with how many indexes each is used with
+pub fn find_arrays(stmt: &DBStmt, arrays: &mut Vec<(String, usize)>) {
+    visit_stmt(stmt, &mut Vec::new(), arrays);
+    if let DBStmt::IF { ref then_stmts, ref else_stmts, .. } = *stmt {
+        for s in then_stmts.iter().chain(else_stmts.iter()) {
+            find_arrays(s, arrays);
+        }
+    }
+}
+
+// whether any PRINT uses TAB, which needs to know the output column
+pub fn uses_tab(blocks: &[Block]) -> bool {
+    return blocks.iter()
+        .flat_map(|block| block.cmds.iter())
+        .any(|cmd| match cmd.cmd {
+            DBStmt::PRINT { ref seq, .. } => seq.iter().any(|expr| match *expr {
+                DBExpr::E(Expr::F(ref name, _)) => name == "TAB",
+                _ => false
+            }),
+            _ => false
+        });
+}
+
+fn get_dim_sizes(array: &DBArrayDef) -> Vec<usize> {
+    let mut to_r = Vec::new();
+    for expr in array.dims.iter() {
+        match *expr {
+            Expr::N(num) => {
+                if num < 1 {
+                    panic!("Array {} must have all > 0 dimensions",
+                           array.varname);
+                }
+                to_r.push(num as usize);
+            },
+            _ => panic!("DIM statement for array {} must give a fixed size",
+                        array.varname)
+        }
+    }
+
+    return to_r;
+}
+
+// string variables are kept separately, so only numeric names are
+// recorded here
+fn add_var(vars: &mut Vec<String>, name: &str) {
+    if !name.ends_with('$') && !vars.iter().any(|v| v == name) {
+        vars.push(String::from(name));
+    }
+}
+
+fn add_array(arrays: &mut Vec<(String, usize)>, name: &str, num_dims: usize) {
+    if let Some(&(_, prev)) = arrays.iter().find(|a| a.0 == name) {
+        if prev != num_dims {
+            panic!("Array {} is used with both {} and {} indexes",
+                   name, prev, num_dims);
+        }
+        return;
+    }
+
+    arrays.push((String::from(name), num_dims));
+}
+
+fn visit_stmt(stmt: &DBStmt, vars: &mut Vec<String>,
+              arrays: &mut Vec<(String, usize)>) {
+    match *stmt {
+        DBStmt::FOR { ref varname, ref from_expr, ref to_expr, .. } => {
+            add_var(vars, varname);
+            visit_db_expr(from_expr, vars, arrays);
+            visit_db_expr(to_expr, vars, arrays);
+        },
+
+        DBStmt::WHILE { ref expr1, ref expr2, .. }
+        | DBStmt::LOOP { ref expr1, ref expr2, .. }
+        | DBStmt::IF { ref expr1, ref expr2, .. } => {
+            // the branches of an IF are blocks of their own
+            visit_db_expr(expr1, vars, arrays);
+            visit_db_expr(expr2, vars, arrays);
+        },
+
+        DBStmt::ONGOTO { ref expr, .. }
+        | DBStmt::ONGOSUB { ref expr, .. } => {
+            visit_db_expr(expr, vars, arrays);
+        },
+
+        DBStmt::LET { ref target, ref expr } => {
+            visit_target(target, vars, arrays);
+            visit_db_expr(expr, vars, arrays);
+        },
+
+        DBStmt::READ { ref varnames } => {
+            for target in varnames.iter() {
+                visit_target(target, vars, arrays);
+            }
+        },
+
+        DBStmt::PRINT { ref seq, .. }
+        | DBStmt::PRINTUSING { ref seq, .. } => {
+            for expr in seq.iter() {
+                visit_db_expr(expr, vars, arrays);
+            }
+        },
+
+        // the function's own variable is replaced by its argument
+        DBStmt::DEF { ref varname, ref expr, .. } => {
+            let mut body_vars = Vec::new();
+            visit_db_expr(expr, &mut body_vars, arrays);
+            for var in body_vars.iter().filter(|v| *v != varname) {
+                add_var(vars, var);
+            }
+        },
+
+        _ => {}
+    }
+}
+
+fn visit_target(target: &DBLetTarget, vars: &mut Vec<String>,
+                arrays: &mut Vec<(String, usize)>) {
+    match *target {
+        DBLetTarget::VAR(ref name) => add_var(vars, name),
+        DBLetTarget::ARR(ref def) => visit_array(def, vars, arrays)
+    }
+}
+
+fn visit_array(def: &DBArrayDef, vars: &mut Vec<String>,
+               arrays: &mut Vec<(String, usize)>) {
+    add_array(arrays, &def.varname, def.dims.len());
+    for dim in def.dims.iter() {
+        visit_expr(dim, vars, arrays);
+    }
+}
+
+fn visit_db_expr(expr: &DBExpr, vars: &mut Vec<String>,
+                 arrays: &mut Vec<(String, usize)>) {
+    if let DBExpr::E(ref e) = *expr {
+        visit_expr(e, vars, arrays);
+    }
+}
+
+fn visit_expr(expr: &Expr, vars: &mut Vec<String>,
+              arrays: &mut Vec<(String, usize)>) {
+    match *expr {
+        Expr::O(ref e1, _, ref e2) => {
+            visit_expr(e1, vars, arrays);
+            visit_expr(e2, vars, arrays);
+        },
+        Expr::V(ref name) => add_var(vars, name),
+        Expr::A(ref def) => visit_array(def, vars, arrays),
+        Expr::E(ref e) => visit_expr(e, vars, arrays),
+        Expr::F(_, ref args) => {
+            for arg in args.iter() {
+                visit_expr(arg, vars, arrays);
+            }
+        },
+        Expr::N(_) | Expr::D(_) | Expr::S(_) => {}
+    }
+}
@@ -0,0 +1,566 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use parser::structs::{DBCommand, DBStmt, DBExpr, DBLetTarget, DBMatExpr};
+use parser::ast::Expr;
+use ir::blockgen::{build_blocks, reachable, Block, SpecialOut};
+
+// a likely mistake in a program that still compiles
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Warning {
+    pub ln: u32,
+    pub message: String
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "line {}: {}", self.ln, self.message);
+    }
+}
+
+// arrays are kept apart from numbers and strings with the same
+// name, and DEF functions from arrays
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Var {
+    Scalar(String),
+    Array(String),
+    Function(String)
+}
+
+impl fmt::Display for Var {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            Var::Scalar(ref name) => write!(f, "{}", name),
+            Var::Array(ref name) => write!(f, "array {}", name),
+            Var::Function(ref name) => write!(f, "function {}", name)
+        };
+    }
+}
+
+// finds the warnings of a program, in line order
+pub fn lint(stmts: Vec<DBCommand>) -> Vec<Warning> {
+    let mut warnings = BTreeSet::new();
+
+    let flat = flatten(&stmts);
+    let functions = functions(&flat);
+    never_read(&flat, &functions, &mut warnings);
+    loop_var_changes(&flat, &mut warnings);
+    uncalled_functions(&flat, &functions, &mut warnings);
+    let missing = subroutines_without_return(&flat, &mut warnings);
+
+    // the blocks can't be linked to a subroutine without a RETURN,
+    // so going to one is treated as a GOTO, which is what it does
+    let stmts: Vec<DBCommand> = stmts.iter()
+        .map(|c| DBCommand { cmd: without_returns(&c.cmd, &missing), ..c.clone() })
+        .collect();
+    let (blocks, implicit_end) = build_blocks(stmts);
+    let reached = reachable(&blocks);
+    unreachable_lines(&blocks, &reached, implicit_end, &mut warnings);
+    uninitialized_reads(&blocks, &reached, &functions, &mut warnings);
+
+    return warnings.into_iter().collect();
+}
+
+// every statement with its line, including the ones inside the
+// branches of an IF, in program order
+fn flatten(cmds: &[DBCommand]) -> Vec<(u32, &DBStmt)> {
+    fn add<'a>(ln: u32, stmt: &'a DBStmt, to: &mut Vec<(u32, &'a DBStmt)>) {
+        to.push((ln, stmt));
+        if let DBStmt::IF { ref then_stmts, ref else_stmts, .. } = *stmt {
+            for s in then_stmts.iter().chain(else_stmts.iter()) {
+                add(ln, s, to);
+            }
+        }
+    }
+
+    let mut to_r = Vec::new();
+    for cmd in cmds.iter() {
+        add(cmd.ln, &cmd.cmd, &mut to_r);
+    }
+    return to_r;
+}
+
+fn expr_reads(expr: &Expr, reads: &mut Vec<Var>) {
+    match *expr {
+        Expr::O(ref e1, _, ref e2) => {
+            expr_reads(e1, reads);
+            expr_reads(e2, reads);
+        },
+        Expr::V(ref name) => reads.push(Var::Scalar(name.clone())),
+        Expr::A(ref def) => {
+            reads.push(Var::Array(def.varname.clone()));
+            for dim in def.dims.iter() {
+                expr_reads(dim, reads);
+            }
+        },
+        Expr::E(ref e) => expr_reads(e, reads),
+        Expr::F(_, ref args) => {
+            for arg in args.iter() {
+                expr_reads(arg, reads);
+            }
+        },
+        Expr::N(_) | Expr::D(_) | Expr::S(_) => {}
+    };
+}
+
+fn db_expr_reads(expr: &DBExpr, reads: &mut Vec<Var>) {
+    if let DBExpr::E(ref e) = *expr {
+        expr_reads(e, reads);
+    }
+}
+
+fn target_uses(target: &DBLetTarget, reads: &mut Vec<Var>, writes: &mut Vec<Var>) {
+    match *target {
+        DBLetTarget::VAR(ref name) => writes.push(Var::Scalar(name.clone())),
+        DBLetTarget::ARR(ref def) => {
+            for dim in def.dims.iter() {
+                expr_reads(dim, reads);
+            }
+            writes.push(Var::Array(def.varname.clone()));
+        }
+    };
+}
+
+// the variables a statement reads and the ones it gives a value,
+// not counting the statements in the branches of an IF. The body
+// of a DEF is only read when the function is called.
+fn stmt_uses(stmt: &DBStmt) -> (Vec<Var>, Vec<Var>) {
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+
+    match *stmt {
+        // a DIMed array starts out as zeros
+        DBStmt::DIM { ref arrays } => {
+            for array in arrays.iter() {
+                for dim in array.dims.iter() {
+                    expr_reads(dim, &mut reads);
+                }
+                writes.push(Var::Array(array.varname.clone()));
+            }
+        },
+
+        DBStmt::FOR { ref varname, ref from_expr, ref to_expr, ref step_expr } => {
+            db_expr_reads(from_expr, &mut reads);
+            db_expr_reads(to_expr, &mut reads);
+            if let Some(ref step) = **step_expr {
+                db_expr_reads(step, &mut reads);
+            }
+            writes.push(Var::Scalar(varname.clone()));
+        },
+
+        // NEXT adds to the loop variable
+        DBStmt::NEXT { ref varname } => {
+            reads.push(Var::Scalar(varname.clone()));
+            writes.push(Var::Scalar(varname.clone()));
+        },
+
+        DBStmt::WHILE { ref expr1, ref expr2, .. }
+        | DBStmt::LOOP { ref expr1, ref expr2, .. }
+        | DBStmt::IF { ref expr1, ref expr2, .. } => {
+            db_expr_reads(expr1, &mut reads);
+            db_expr_reads(expr2, &mut reads);
+        },
+
+        DBStmt::ONGOTO { ref expr, .. } | DBStmt::ONGOSUB { ref expr, .. } => {
+            db_expr_reads(expr, &mut reads);
+        },
+
+        DBStmt::LET { ref target, ref expr } => {
+            db_expr_reads(expr, &mut reads);
+            target_uses(target, &mut reads, &mut writes);
+        },
+
+        DBStmt::PRINT { ref seq, .. } | DBStmt::PRINTUSING { ref seq, .. } => {
+            for item in seq.iter() {
+                db_expr_reads(item, &mut reads);
+            }
+        },
+
+        DBStmt::READ { ref varnames } => {
+            for target in varnames.iter() {
+                target_uses(target, &mut reads, &mut writes);
+            }
+        },
+
+        DBStmt::MATREAD { ref arrays } => {
+            writes.extend(arrays.iter().map(|a| Var::Array(a.clone())));
+        },
+
+        DBStmt::MATPRINT { ref arrays } => {
+            reads.extend(arrays.iter().map(|a| Var::Array(a.clone())));
+        },
+
+        DBStmt::MAT { ref target, ref expr } => {
+            match *expr {
+                DBMatExpr::COPY(ref a) => reads.push(Var::Array(a.clone())),
+                DBMatExpr::ADD(ref a, ref b)
+                | DBMatExpr::SUB(ref a, ref b)
+                | DBMatExpr::MUL(ref a, ref b) => {
+                    reads.push(Var::Array(a.clone()));
+                    reads.push(Var::Array(b.clone()));
+                },
+                DBMatExpr::ZER | DBMatExpr::CON | DBMatExpr::IDN => {}
+            };
+            writes.push(Var::Array(target.clone()));
+        },
+
+        _ => {}
+    };
+
+    return (reads, writes);
+}
+
+// the DEF functions of a program, with what their bodies read
+type Functions = HashMap<String, Vec<Var>>;
+
+fn functions(flat: &[(u32, &DBStmt)]) -> Functions {
+    return flat.iter()
+        .filter_map(|&(_, stmt)| match *stmt {
+            DBStmt::DEF { ref funcname, ref varname, ref expr } => {
+                let mut body = Vec::new();
+                db_expr_reads(expr, &mut body);
+                body.retain(|v| *v != Var::Scalar(varname.clone()));
+                Some((funcname.clone(), body))
+            },
+            _ => None
+        })
+        .collect();
+}
+
+// reads, with the calls to DEF functions (which look like arrays)
+// changed to the function and what its body reads
+fn with_calls(reads: Vec<Var>, functions: &Functions) -> Vec<Var> {
+    let mut to_r = Vec::new();
+    let mut todo = reads;
+    while let Some(var) = todo.pop() {
+        let name = match var {
+            Var::Array(ref name) if functions.contains_key(name) => name.clone(),
+            _ => {
+                to_r.push(var);
+                continue;
+            }
+        };
+
+        // a function that calls itself is only followed once
+        let call = Var::Function(name.clone());
+        if !to_r.contains(&call) {
+            todo.extend(functions[&name].iter().cloned());
+            to_r.push(call);
+        }
+    }
+    return to_r;
+}
+
+// every variable and function the program reads, including in the
+// bodies of DEFs
+fn all_reads(flat: &[(u32, &DBStmt)], functions: &Functions) -> HashSet<Var> {
+    let mut read = HashSet::new();
+    for &(_, stmt) in flat.iter() {
+        read.extend(with_calls(stmt_uses(stmt).0, functions));
+    }
+    for body in functions.values() {
+        read.extend(with_calls(body.clone(), functions));
+    }
+    return read;
+}
+
+// variables that are given a value but never read
+fn never_read(flat: &[(u32, &DBStmt)], functions: &Functions,
+              warnings: &mut BTreeSet<Warning>) {
+    let read = all_reads(flat, functions);
+    let mut first_set = BTreeMap::new();
+    for &(ln, stmt) in flat.iter() {
+        for var in stmt_uses(stmt).1 {
+            first_set.entry(var).or_insert(ln);
+        }
+    }
+
+    for (var, ln) in first_set {
+        if !read.contains(&var) {
+            warnings.insert(Warning { ln, message: format!("{} is never read", var) });
+        }
+    }
+}
+
+// statements between a FOR and its NEXT that change the loop variable
+fn loop_var_changes(flat: &[(u32, &DBStmt)], warnings: &mut BTreeSet<Warning>) {
+    for (idx, &(for_ln, stmt)) in flat.iter().enumerate() {
+        let varname = match *stmt {
+            DBStmt::FOR { ref varname, .. } => varname,
+            _ => continue
+        };
+        let var = Var::Scalar(varname.clone());
+
+        for &(ln, inner) in flat[idx + 1..].iter() {
+            if let DBStmt::NEXT { varname: ref next } = *inner {
+                if next == varname {
+                    break;
+                }
+            }
+
+            if stmt_uses(inner).1.contains(&var) {
+                let message = format!("FOR variable {} is changed inside the loop \
+                                       started on line {}", varname, for_ln);
+                warnings.insert(Warning { ln, message });
+            }
+        }
+    }
+}
+
+fn uncalled_functions(flat: &[(u32, &DBStmt)], functions: &Functions,
+                      warnings: &mut BTreeSet<Warning>) {
+    let read = all_reads(flat, functions);
+    for &(ln, stmt) in flat.iter() {
+        if let DBStmt::DEF { ref funcname, .. } = *stmt {
+            if !read.contains(&Var::Function(funcname.clone())) {
+                let message = format!("function {} is never called", funcname);
+                warnings.insert(Warning { ln, message });
+            }
+        }
+    }
+}
+
+// a subroutine needs a RETURN on or after the line it starts on.
+// Returns the lines of the ones that don't have one.
+// Option::is_none_or would need a newer Rust.
+#[allow(clippy::unnecessary_map_or)]
+fn subroutines_without_return(flat: &[(u32, &DBStmt)],
+                              warnings: &mut BTreeSet<Warning>) -> HashSet<u32> {
+    let mut missing = HashSet::new();
+    let last_return = flat.iter()
+        .filter(|&&(_, stmt)| matches!(*stmt, DBStmt::RETURN))
+        .map(|&(ln, _)| ln)
+        .max();
+
+    for &(ln, stmt) in flat.iter() {
+        let targets = match *stmt {
+            DBStmt::GOSUB { lineno } => vec![lineno],
+            DBStmt::ONGOSUB { ref linenos, .. } => linenos.clone(),
+            _ => continue
+        };
+
+        for target in targets {
+            if last_return.map_or(true, |r| r < target) {
+                let message = format!("GOSUB {} goes to a subroutine without a RETURN",
+                                      target);
+                warnings.insert(Warning { ln, message });
+                missing.insert(target);
+            }
+        }
+    }
+
+    return missing;
+}
+
+// stmt with its GOSUBs to the subroutines in missing changed to GOTOs.
+// An ON GOSUB leaves those out, unless they are all it goes to.
+fn without_returns(stmt: &DBStmt, missing: &HashSet<u32>) -> DBStmt {
+    return match *stmt {
+        DBStmt::GOSUB { lineno } if missing.contains(&lineno) =>
+            DBStmt::GOTO { lineno },
+
+        DBStmt::ONGOSUB { ref expr, ref linenos } => {
+            let kept: Vec<u32> = linenos.iter()
+                .filter(|ln| !missing.contains(ln))
+                .cloned()
+                .collect();
+            if kept.is_empty() {
+                DBStmt::ONGOTO { expr: expr.clone(), linenos: linenos.clone() }
+            } else {
+                DBStmt::ONGOSUB { expr: expr.clone(), linenos: kept }
+            }
+        },
+
+        DBStmt::IF { ref then_stmts, ref else_stmts, .. } => {
+            let mut to_r = stmt.clone();
+            if let DBStmt::IF { then_stmts: ref mut t, else_stmts: ref mut e, .. } = to_r {
+                *t = then_stmts.iter().map(|s| without_returns(s, missing)).collect();
+                *e = else_stmts.iter().map(|s| without_returns(s, missing)).collect();
+            }
+            to_r
+        },
+
+        _ => stmt.clone()
+    };
+}
+
+// the blocks control can go to after a block. A DO loop always runs
+// its body, so only its LOOP goes past it.
+fn successors(blocks: &[Block], idx: usize) -> Vec<usize> {
+    let b = &blocks[idx];
+    let is_do = matches!(b.cmds.last().map(|c| &c.cmd), Some(&DBStmt::DO));
+
+    let mut to_r: Vec<usize> = if is_do {
+        b.out_blocks.iter().skip(1).cloned().collect()
+    } else {
+        b.out_blocks.clone()
+    };
+
+    match b.special_out {
+        SpecialOut::Next(start) => {
+            to_r.push(start);
+            let loops_do = matches!(blocks[start].cmds.last().map(|c| &c.cmd),
+                                    Some(&DBStmt::DO));
+            if loops_do {
+                to_r.push(blocks[start].out_blocks[0]);
+            }
+        },
+        // control comes back through the subroutine's RETURN
+        SpecialOut::Return(_) | SpecialOut::None => {}
+    };
+
+    return to_r;
+}
+
+// the lines that are never reached, in runs, with a warning that
+// they were left out
+pub fn unreached(blocks: &[Block], implicit_end: Option<usize>) -> Vec<Warning> {
+    return unreached_runs(blocks, &reachable(blocks), implicit_end).into_iter()
+        .map(|(first, last)| {
+            let message = if first == last {
+                String::from("this line is never reached, so it was left out")
+            } else {
+                format!("lines {} to {} are never reached, so they were left out",
+                        first, last)
+            };
+            Warning { ln: first, message }
+        })
+        .collect();
+}
+
+// runs of lines that control never gets to
+fn unreachable_lines(blocks: &[Block], reached: &HashSet<usize>,
+                     implicit_end: Option<usize>,
+                     warnings: &mut BTreeSet<Warning>) {
+    for (first, last) in unreached_runs(blocks, reached, implicit_end) {
+        let message = if first == last {
+            String::from("this line is never reached")
+        } else {
+            format!("lines {} to {} are never reached", first, last)
+        };
+        warnings.insert(Warning { ln: first, message });
+    }
+}
+
+// the first and last line of each run of lines control never gets
+// to, in line order
+fn unreached_runs(blocks: &[Block], reached: &HashSet<usize>,
+                  implicit_end: Option<usize>) -> Vec<(u32, u32)> {
+    let mut lines = BTreeMap::new();
+    for (idx, b) in blocks.iter().enumerate() {
+        if Some(idx) == implicit_end {
+            continue;
+        }
+        for cmd in b.cmds.iter() {
+            *lines.entry(cmd.ln).or_insert(false) |= reached.contains(&idx);
+        }
+    }
+
+    let mut to_r = Vec::new();
+    let mut run: Vec<u32> = Vec::new();
+    let mut lines: Vec<(u32, bool)> = lines.into_iter().collect();
+    lines.push((u32::MAX, true));
+    for (ln, is_reached) in lines {
+        if !is_reached {
+            run.push(ln);
+            continue;
+        }
+
+        if let (Some(&first), Some(&last)) = (run.first(), run.last()) {
+            to_r.push((first, last));
+        }
+        run.clear();
+    }
+    return to_r;
+}
+
+// variables that can be read before they are given a value, going
+// by the variables every path to a block gives a value
+fn uninitialized_reads(blocks: &[Block], reached: &HashSet<usize>,
+                       functions: &Functions,
+                       warnings: &mut BTreeSet<Warning>) {
+    let mut preds = vec![Vec::new(); blocks.len()];
+    for &idx in reached.iter() {
+        for s in successors(blocks, idx) {
+            preds[s].push(idx);
+        }
+    }
+
+    let writes: Vec<HashSet<Var>> = blocks.iter()
+        .map(|b| b.cmds.iter().flat_map(|c| stmt_uses(&c.cmd).1).collect())
+        .collect();
+
+    // None means every variable, until a path to the block is known
+    let mut set_in: Vec<Option<HashSet<Var>>> = vec![None; blocks.len()];
+    set_in[0] = Some(HashSet::new());
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for idx in 1..blocks.len() {
+            if !reached.contains(&idx) {
+                continue;
+            }
+
+            let mut new_in: Option<HashSet<Var>> = None;
+            for &p in preds[idx].iter() {
+                let out: HashSet<Var> = match set_in[p] {
+                    Some(ref s) => s.union(&writes[p]).cloned().collect(),
+                    None => continue
+                };
+                new_in = Some(match new_in {
+                    Some(s) => s.intersection(&out).cloned().collect(),
+                    None => out
+                });
+            }
+
+            if new_in.is_some() && new_in != set_in[idx] {
+                set_in[idx] = new_in;
+                changed = true;
+            }
+        }
+    }
+
+    // each variable is reported once, on the first line found
+    let mut first_read = BTreeMap::new();
+    for (idx, b) in blocks.iter().enumerate() {
+        let mut set = match set_in[idx] {
+            Some(ref s) if reached.contains(&idx) => s.clone(),
+            _ => continue
+        };
+
+        for cmd in b.cmds.iter() {
+            let (reads, writes) = stmt_uses(&cmd.cmd);
+            for var in with_calls(reads, functions) {
+                let is_call = matches!(var, Var::Function(_));
+                if !is_call && !set.contains(&var) {
+                    let ln = first_read.entry(var).or_insert(cmd.ln);
+                    *ln = (*ln).min(cmd.ln);
+                }
+            }
+            set.extend(writes);
+        }
+    }
+
+    for (var, ln) in first_read {
+        let message = format!("{} can be read before it is given a value", var);
+        warnings.insert(Warning { ln, message });
+    }
+}
@@ -0,0 +1,334 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::HashMap;
+use parser::structs::{DBCommand, DBStmt, DBExpr, DBLetTarget,
+                      DBArrayDef, DBMatExpr, DBPrintSep};
+use parser::ast::{Expr, OpCode};
+use ir::layout::{self, DEFAULT_ARRAY_SIZE};
+
+// MAT statements work on whole arrays. Each one is expanded into
+// FOR loops over the elements, sharing the line number of the MAT
+// statement, before the program is split into blocks.
+pub fn expand_mat(cmds: Vec<DBCommand>) -> Vec<DBCommand> {
+    let dims = get_array_dims(&cmds);
+    let mut to_r = Vec::new();
+
+    for cmd in cmds {
+        let ln = cmd.ln;
+        to_r.extend(expand_stmt(&dims, ln, cmd.cmd).into_iter()
+                    .map(|stmt| DBCommand { ln: ln,
+                                            cmd: stmt,
+                                            data: Vec::new() }));
+    }
+
+    return to_r;
+}
+
+fn expand_stmt(dims: &HashMap<String, Vec<usize>>, ln: u32,
+               stmt: DBStmt) -> Vec<DBStmt> {
+    return match stmt {
+        DBStmt::MATREAD { ref arrays } => expand_read(dims, arrays),
+        DBStmt::MATPRINT { ref arrays } => expand_print(dims, arrays),
+        DBStmt::MAT { ref target, ref expr } =>
+            expand_assign(dims, ln, target, expr),
+
+        // the branches of a single-line IF can hold MAT statements too
+        DBStmt::IF { expr1, op, expr2, then_stmts, else_stmts } => {
+            vec![DBStmt::IF {
+                expr1: expr1,
+                op: op,
+                expr2: expr2,
+                then_stmts: expand_stmts(dims, ln, then_stmts),
+                else_stmts: expand_stmts(dims, ln, else_stmts)
+            }]
+        },
+
+        _ => vec![stmt]
+    };
+}
+
+fn expand_stmts(dims: &HashMap<String, Vec<usize>>, ln: u32,
+                stmts: Vec<DBStmt>) -> Vec<DBStmt> {
+    return stmts.into_iter()
+        .flat_map(|stmt| expand_stmt(dims, ln, stmt))
+        .collect();
+}
+
+// the dimensions of every array, from the DIM statements. Arrays
+// without a DIM have the default size, in as many dimensions as
+// they are indexed with.
+fn get_array_dims(cmds: &[DBCommand]) -> HashMap<String, Vec<usize>> {
+    let mut used = Vec::new();
+    for cmd in cmds.iter() {
+        layout::find_arrays(&cmd.cmd, &mut used);
+    }
+
+    let mut to_r: HashMap<String, Vec<usize>> = used.into_iter()
+        .map(|(name, num_dims)| (name, vec![DEFAULT_ARRAY_SIZE; num_dims]))
+        .collect();
+    for cmd in cmds.iter() {
+        if let DBStmt::DIM { ref arrays } = cmd.cmd {
+            for array in arrays.iter() {
+                // DIM checks the sizes itself
+                let sizes: Option<Vec<usize>> = array.dims.iter()
+                    .map(|d| match *d {
+                        Expr::N(n) if n > 0 => Some(n as usize),
+                        _ => None
+                    })
+                    .collect();
+
+                if let Some(sizes) = sizes {
+                    to_r.insert(array.varname.clone(), sizes);
+                }
+            }
+        }
+    }
+
+    return to_r;
+}
+
+// an array that is only ever used by MAT statements is a matrix
+fn get_dims(dims: &HashMap<String, Vec<usize>>, name: &str) -> Vec<usize> {
+    return dims.get(name).cloned()
+        .unwrap_or_else(|| vec![DEFAULT_ARRAY_SIZE; 2]);
+}
+
+fn get_matrix_dims(dims: &HashMap<String, Vec<usize>>, ln: u32,
+                   name: &str) -> (usize, usize) {
+    let d = get_dims(dims, name);
+    if d.len() != 2 {
+        panic!("MAT statement on line {} needs array {} to have \
+                two dimensions", ln, name);
+    }
+
+    return (d[0], d[1]);
+}
+
+fn check_same_dims(dims: &HashMap<String, Vec<usize>>, ln: u32,
+                   name1: &str, name2: &str) {
+    if get_dims(dims, name1) != get_dims(dims, name2) {
+        panic!("MAT statement on line {} uses arrays {} and {}, which \
+                have different dimensions", ln, name1, name2);
+    }
+}
+
+// the hidden loop variables can't clash with a BASIC name
+fn loop_var(depth: usize) -> String {
+    return format!("MAT.{}", depth);
+}
+
+fn loop_index(depth: usize) -> Expr {
+    return Expr::V(loop_var(depth));
+}
+
+fn element(name: &str, idx: Vec<Expr>) -> Expr {
+    return Expr::A(DBArrayDef { varname: String::from(name), dims: idx });
+}
+
+// a PRINT that never adds a newline of its own
+fn print(seq: Vec<DBExpr>) -> DBStmt {
+    let seps = vec![DBPrintSep::Semicolon; seq.len()];
+    return DBStmt::PRINT { seq: seq, seps: seps };
+}
+
+fn let_element(name: &str, idx: Vec<Expr>, expr: Expr) -> DBStmt {
+    return DBStmt::LET {
+        target: DBLetTarget::ARR(DBArrayDef { varname: String::from(name),
+                                              dims: idx }),
+        expr: DBExpr::E(expr)
+    };
+}
+
+// wraps body in a loop of the variable at depth from start up to
+// (but not including) end
+fn wrap_loop(depth: usize, start: usize, end: usize,
+             body: Vec<DBStmt>) -> Vec<DBStmt> {
+    let mut to_r = vec![DBStmt::FOR {
+        varname: loop_var(depth),
+        from_expr: DBExpr::E(Expr::N(start as i32)),
+        to_expr: DBExpr::E(Expr::N(end as i32)),
+        step_expr: Box::new(None)
+    }];
+    to_r.extend(body);
+    to_r.push(DBStmt::NEXT { varname: loop_var(depth) });
+    return to_r;
+}
+
+// runs body for every element, with the loop variables 0, 1, ...
+// indexing each dimension
+fn for_each_element(sizes: &[usize], body: Vec<DBStmt>) -> Vec<DBStmt> {
+    let mut to_r = body;
+    for (depth, size) in sizes.iter().enumerate().rev() {
+        to_r = wrap_loop(depth, 0, *size, to_r);
+    }
+    return to_r;
+}
+
+fn all_indexes(sizes: &[usize]) -> Vec<Expr> {
+    return (0..sizes.len()).map(loop_index).collect();
+}
+
+// every READ gets its DATA when the program is compiled, so the
+// elements are read by one READ instead of a loop.
+fn expand_read(dims: &HashMap<String, Vec<usize>>,
+               arrays: &[String]) -> Vec<DBStmt> {
+    let mut targets = Vec::new();
+    for name in arrays.iter() {
+        let sizes = get_dims(dims, name);
+        let total: usize = sizes.iter().product();
+
+        for flat in 0..total {
+            let mut idx = Vec::new();
+            let mut rest = flat;
+            for size in sizes.iter().rev() {
+                idx.push(Expr::N((rest % size) as i32));
+                rest /= size;
+            }
+            idx.reverse();
+
+            targets.push(DBLetTarget::ARR(DBArrayDef {
+                varname: name.clone(),
+                dims: idx
+            }));
+        }
+    }
+
+    return vec![DBStmt::READ { varnames: targets }];
+}
+
+// prints each row of an array on its own line, with the elements
+// separated by spaces
+fn expand_print(dims: &HashMap<String, Vec<usize>>,
+                arrays: &[String]) -> Vec<DBStmt> {
+    let mut to_r = Vec::new();
+    for name in arrays.iter() {
+        let sizes = &get_dims(dims, name);
+        let last = sizes.len() - 1;
+
+        let mut first_idx = all_indexes(&sizes[..last]);
+        first_idx.push(Expr::N(0));
+        let rest_idx = all_indexes(sizes);
+
+        let mut row = vec![print(vec![DBExpr::E(element(name, first_idx))])];
+        row.extend(wrap_loop(last, 1, sizes[last], vec![
+            print(vec![DBExpr::S(String::from(" ")),
+                       DBExpr::E(element(name, rest_idx))])
+        ]));
+        row.push(print(vec![DBExpr::S(String::from("\n"))]));
+
+        to_r.extend(for_each_element(&sizes[..last], row));
+    }
+
+    return to_r;
+}
+
+fn expand_assign(dims: &HashMap<String, Vec<usize>>, ln: u32,
+                 target: &str, expr: &DBMatExpr) -> Vec<DBStmt> {
+    let sizes = &get_dims(dims, target);
+    let idx = all_indexes(sizes);
+
+    return match *expr {
+        DBMatExpr::ZER | DBMatExpr::CON => {
+            let val = match *expr {
+                DBMatExpr::CON => 1,
+                _ => 0
+            };
+            for_each_element(sizes, vec![
+                let_element(target, idx, Expr::N(val))
+            ])
+        },
+
+        DBMatExpr::IDN => {
+            let (rows, cols) = get_matrix_dims(dims, ln, target);
+            if rows != cols {
+                panic!("MAT statement on line {} needs array {} to be \
+                        square for IDN", ln, target);
+            }
+
+            let mut to_r = for_each_element(sizes, vec![
+                let_element(target, idx, Expr::N(0))
+            ]);
+            to_r.extend(wrap_loop(0, 0, rows, vec![
+                let_element(target, vec![loop_index(0), loop_index(0)],
+                            Expr::N(1))
+            ]));
+            to_r
+        },
+
+        DBMatExpr::COPY(ref src) => {
+            check_same_dims(dims, ln, target, src);
+            for_each_element(sizes, vec![
+                let_element(target, idx.clone(), element(src, idx))
+            ])
+        },
+
+        DBMatExpr::ADD(ref a, ref b) | DBMatExpr::SUB(ref a, ref b) => {
+            check_same_dims(dims, ln, target, a);
+            check_same_dims(dims, ln, target, b);
+            let op = match *expr {
+                DBMatExpr::ADD(..) => OpCode::Add,
+                _ => OpCode::Sub
+            };
+
+            for_each_element(sizes, vec![
+                let_element(target, idx.clone(),
+                            Expr::O(Box::new(element(a, idx.clone())),
+                                    op,
+                                    Box::new(element(b, idx))))
+            ])
+        },
+
+        DBMatExpr::MUL(ref a, ref b) => {
+            let (rows, inner) = get_matrix_dims(dims, ln, a);
+            let (inner_b, cols) = get_matrix_dims(dims, ln, b);
+            let (rows_c, cols_c) = get_matrix_dims(dims, ln, target);
+            if inner != inner_b || rows != rows_c || cols != cols_c {
+                panic!("MAT statement on line {} multiplies arrays {} and \
+                        {} with incompatible dimensions", ln, a, b);
+            }
+
+            // the product is built up in place, so the target can't
+            // also be an input
+            if target == a || target == b {
+                panic!("MAT statement on line {} must not multiply into \
+                        one of its inputs", ln);
+            }
+
+            // C(i, j) = C(i, j) + A(i, k) * B(k, j)
+            let (i, j, k) = (loop_index(0), loop_index(1), loop_index(2));
+            let product = Expr::O(
+                Box::new(element(a, vec![i.clone(), k.clone()])),
+                OpCode::Times,
+                Box::new(element(b, vec![k, j.clone()])));
+            let sum = Expr::O(
+                Box::new(element(target, vec![i.clone(), j.clone()])),
+                OpCode::Add,
+                Box::new(product));
+
+            let mut cell = vec![let_element(target, vec![i.clone(), j.clone()],
+                                            Expr::N(0))];
+            cell.extend(wrap_loop(2, 0, inner, vec![
+                let_element(target, vec![i, j], sum)
+            ]));
+
+            wrap_loop(0, 0, rows, wrap_loop(1, 0, cols, cell))
+        }
+    };
+}
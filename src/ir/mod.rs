@@ -25,84 +25,87 @@ mod allocator;
 mod blockgen;
 mod block_to_ir;
 mod goto_elim;
+mod graphviz;
+mod layout;
+mod lint;
+mod mat;
+mod renum;
 
 use std::io::Write;
-use self::blockgen::{Block, SpecialOut};
+pub use self::blockgen::Block;
+use self::blockgen::SpecialOut;
 use parser::structs::{DBCommand};
 use codegen::{BFQuad};
 use self::goto_elim::{eliminate_gotos};
 
+pub use self::block_to_ir::Options;
+pub use self::layout::Layout;
+pub use self::graphviz::{to_graphviz, to_dominator_graphviz};
+pub use self::renum::renumber;
+pub use self::lint::{lint, Warning};
+
 pub fn elim_gotos(stmts: &mut Vec<Block>) {
     return eliminate_gotos(stmts);
 }
 
-pub fn to_blocks(stmts: Vec<DBCommand>) -> Vec<Block> {
-    let res = blockgen::to_blocks(stmts);
-    return res;
+// splits a program into blocks. Code that is never reached is left
+// out, with a warning for each run of lines, or with strict is an
+// error.
+pub fn to_blocks(stmts: Vec<DBCommand>, strict: bool) -> (Vec<Block>, Vec<Warning>) {
+    let stmts = mat::expand_mat(stmts);
+    if strict {
+        return (blockgen::to_blocks(stmts), Vec::new());
+    }
+
+    let (blocks, implicit_end) = blockgen::build_blocks(stmts);
+    let warnings = lint::unreached(&blocks, implicit_end);
+    return (blockgen::strip_dead_code(blocks, implicit_end), warnings);
 }
 
-pub fn to_ir(blocks: Vec<Block>, const_opt: bool) -> Vec<BFQuad> {
-    let mut ir_gen = block_to_ir::BlockToIR::new(blocks, const_opt);
+// where the compiled program keeps its variables
+pub fn to_layout(blocks: Vec<Block>, const_opt: bool,
+                 options: &Options) -> Layout {
+    let mut ir_gen = block_to_ir::BlockToIR::new(blocks, const_opt,
+                                                 options.clone());
+    ir_gen.generate_ir();
+    return ir_gen.get_layout();
+}
+
+pub fn to_ir(blocks: Vec<Block>, const_opt: bool,
+             options: &Options) -> Vec<BFQuad> {
+    let mut ir_gen = block_to_ir::BlockToIR::new(blocks, const_opt,
+                                                 options.clone());
     ir_gen.generate_ir();
     return ir_gen.get_ir();
 }
 
-pub fn to_graphviz(blocks: &[Block]) -> String{
+// lists each block with its edges and commands
+pub fn dump_blocks(blocks: &[Block]) -> String {
     let mut w = Vec::new();
 
-    write!(&mut w, "digraph G {{\n").unwrap();
-
     for (idx, b) in blocks.iter().enumerate() {
-        if b.is_loop {
-            let lno_last;
-            let cmd_last;
-            {
-                let last = get_last_cmd!(b);
-                lno_last = last.ln;
-                cmd_last = last.cmd;
-            }
-            
-            let lno_first;
-            {
-                let first = get_first_cmd!(b);
-                lno_first = first.ln;
-            }
-            
-            write!(&mut w, "{} [label=\"{}: {} - {} ({})\"];\n",
-                   idx, idx, lno_first, lno_last,
-                   cmd_last.get_string_type()).unwrap();
-        } else {
-
-            write!(&mut w, "{} [label=\"Loop\"];\n",
-                   idx).unwrap();
-        }
+        write!(&mut w, "block {}{}: in {:?}, out {:?}",
+               idx, if b.is_loop { " (loop)" } else { "" },
+               b.in_blocks, b.out_blocks).unwrap();
 
-    }
+        match b.special_out {
+            SpecialOut::Next(pos) => write!(&mut w, ", next {}", pos).unwrap(),
+            SpecialOut::Return(pos) => write!(&mut w, ", return to {}", pos).unwrap(),
+            SpecialOut::None => {}
+        };
 
-    for (idx, b) in blocks.iter().enumerate() {
         if b.is_loop {
-            for out_blk in b.out_blocks.iter() {
-                write!(&mut w, "{} -> {};\n", idx, out_blk).unwrap();
-            }
-        } else {
-            
-
-            match b.special_out {
-                SpecialOut::Next (pos) | SpecialOut::Return(pos) => {
-                    write!(&mut w, "{} -> {} [style=dotted];\n",
-                           idx, pos).unwrap();
-                },
-                
-                SpecialOut::None => {}
-            };
-
-            for out_blk in b.out_blocks.iter() {
-                write!(&mut w, "{} -> {};\n", idx, out_blk).unwrap();
-            }
+            let mut nodes: Vec<&usize> = b.loop_nodes.iter().collect();
+            nodes.sort();
+            write!(&mut w, ", exits {:?}, nodes {:?}",
+                   b.loop_exits, nodes).unwrap();
+        }
+        writeln!(&mut w).unwrap();
+
+        for cmd in b.cmds.iter() {
+            writeln!(&mut w, "    {}", cmd).unwrap();
         }
     }
 
-    write!(&mut w, "}}\n").unwrap();
-    
     return String::from_utf8(w).unwrap();
 }
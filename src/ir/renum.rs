@@ -0,0 +1,81 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::HashMap;
+use parser::structs::{DBCommand, DBStmt};
+use ir::blockgen::get_goto_targets;
+
+// gives the lines the numbers start, start + step, ... in order, and
+// changes every jump to match
+pub fn renumber(mut stmts: Vec<DBCommand>, start: u32, step: u32) -> Vec<DBCommand> {
+    if step == 0 {
+        panic!("The step between line numbers must be more than 0");
+    }
+
+    let mut new_lines = HashMap::new();
+    let mut next = start;
+    for cmd in stmts.iter() {
+        if new_lines.contains_key(&cmd.ln) {
+            continue;
+        }
+        new_lines.insert(cmd.ln, next);
+        next = next.checked_add(step)
+            .expect("The new line numbers are too big");
+    }
+
+    // a jump to a line that isn't there can't be renumbered
+    let mut targets: Vec<u32> = get_goto_targets(&stmts).into_iter().collect();
+    targets.sort();
+    for target in targets {
+        if !new_lines.contains_key(&target) {
+            panic!("Jump to line {}, which does not exist", target);
+        }
+    }
+
+    for cmd in stmts.iter_mut() {
+        cmd.ln = new_lines[&cmd.ln];
+        renumber_targets(&mut cmd.cmd, &new_lines);
+    }
+
+    return stmts;
+}
+
+fn renumber_targets(stmt: &mut DBStmt, new_lines: &HashMap<u32, u32>) {
+    match *stmt {
+        DBStmt::GOSUB { ref mut lineno }
+        | DBStmt::GOTO { ref mut lineno } => {
+            *lineno = new_lines[lineno];
+        },
+
+        DBStmt::ONGOTO { ref mut linenos, .. }
+        | DBStmt::ONGOSUB { ref mut linenos, .. } => {
+            for lineno in linenos.iter_mut() {
+                *lineno = new_lines[lineno];
+            }
+        },
+
+        DBStmt::IF { ref mut then_stmts, ref mut else_stmts, .. } => {
+            for s in then_stmts.iter_mut().chain(else_stmts.iter_mut()) {
+                renumber_targets(s, new_lines);
+            }
+        },
+
+        _ => { }
+    };
+}
@@ -0,0 +1,463 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::HashMap;
+use parser;
+use parser::structs::{DBCommand, DBStmt, DBLetTarget};
+use ir;
+use compile;
+use codegen::STRING_CAPACITY;
+
+// words that are never variables
+const KEYWORDS: [&str; 42] = [
+    "LET", "PRINT", "USING", "GOTO", "GOSUB", "RETURN", "IF", "THEN",
+    "ELSE", "FOR", "TO", "STEP", "NEXT", "WHILE", "WEND", "DO", "LOOP",
+    "UNTIL", "END", "STOP", "DIM", "DEF", "DATA", "READ", "MAT", "ON",
+    "REM", "ZER", "CON", "IDN", "LEN", "MID$", "LEFT$", "RIGHT$", "CHR$",
+    "ASC", "STR$", "VAL", "TAB", "SPC", "AND", "OR"
+];
+
+// a place in a document. Like in LSP, lines and columns count
+// from 0, and columns count UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pos {
+    pub line: usize,
+    pub col: usize
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: Pos,
+    pub end: Pos
+}
+
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub span: Span,
+    pub message: String,
+    // warnings don't stop the program from compiling
+    pub is_warning: bool
+}
+
+// what a word in a document refers to
+enum Symbol {
+    // a BASIC line number, where it is defined or jumped to
+    Line(u32),
+    Var(String)
+}
+
+// everything known about one open document
+pub struct Analysis {
+    lines: Vec<String>,
+    // the document line each BASIC line number is on
+    line_numbers: HashMap<u32, usize>,
+    cmds: Vec<DBCommand>,
+    // only known when the program compiles
+    layout: Option<ir::Layout>,
+    pub diagnostics: Vec<Diagnostic>
+}
+
+impl Analysis {
+    pub fn new(text: &str) -> Analysis {
+        let lines: Vec<String> = text.lines().map(String::from).collect();
+        let mut line_numbers = HashMap::new();
+        for (idx, line) in lines.iter().enumerate() {
+            let digits: String = line.trim_start().chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            if let Ok(ln) = digits.parse::<u32>() {
+                line_numbers.entry(ln).or_insert(idx);
+            }
+        }
+
+        let mut to_r = Analysis {
+            lines: lines,
+            line_numbers: line_numbers,
+            cmds: Vec::new(),
+            layout: None,
+            diagnostics: Vec::new()
+        };
+
+        match parser::try_parse_bytes(text.as_bytes()) {
+            Ok(cmds) => {
+                to_r.cmds = cmds;
+                to_r.compile();
+            },
+            Err(err) => {
+                let line = text[..err.offset].matches('\n').count();
+                let span = to_r.line_span(line);
+                to_r.diagnostics.push(Diagnostic { span: span,
+                                                   message: err.to_string(),
+                                                   is_warning: false });
+            }
+        }
+
+        return to_r;
+    }
+
+    // compiling reports errors by panicking, so the panic is caught
+    // and put on the line it mentions
+    fn compile(&mut self) {
+        let cmds = self.cmds.clone();
+        let res = compile::catch_panic(|| {
+            let (mut blocks, warnings) = ir::to_blocks(cmds, false);
+            ir::elim_gotos(&mut blocks);
+            return (ir::to_layout(blocks, true, &ir::Options::default()), warnings);
+        });
+
+        match res {
+            Ok((layout, warnings)) => {
+                self.layout = Some(layout);
+
+                // code that is never reached is left out
+                for warning in warnings {
+                    let span = self.ln_span(Some(warning.ln));
+                    self.diagnostics.push(Diagnostic { span: span,
+                                                       message: warning.message,
+                                                       is_warning: true });
+                }
+            },
+            Err(message) => {
+                // the first number in the message that is a line of
+                // the program. Errors without one cover the whole
+                // document.
+                let ln = message
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter_map(|w| w.parse::<u32>().ok())
+                    .find(|ln| self.line_numbers.contains_key(ln));
+                let span = self.ln_span(ln);
+                self.diagnostics.push(Diagnostic { span: span, message: message,
+                                                   is_warning: false });
+            }
+        }
+    }
+
+    // where a GOTO or GOSUB target is defined, or where a variable is
+    // first given a value
+    pub fn definition(&self, pos: Pos) -> Option<Span> {
+        return match self.symbol_at(pos)? {
+            Symbol::Line(ln) => self.line_number_span(ln),
+            Symbol::Var(name) => {
+                let cmd = self.cmds.iter().find(|c| assigns(&c.cmd, &name))?;
+                let line = *self.line_numbers.get(&cmd.ln)?;
+                let found = self.find_word(line, &name);
+                Some(found.into_iter().next()
+                     .unwrap_or_else(|| self.line_span(line)))
+            }
+        };
+    }
+
+    // every jump to a line number, and its definition if asked for
+    pub fn references(&self, pos: Pos, include_decl: bool) -> Vec<Span> {
+        let ln = match self.symbol_at(pos) {
+            Some(Symbol::Line(ln)) => ln,
+            _ => return Vec::new()
+        };
+
+        let mut to_r = Vec::new();
+        if include_decl {
+            to_r.extend(self.line_number_span(ln));
+        }
+
+        let mut lines: Vec<usize> = self.cmds.iter()
+            .filter(|c| targets(&c.cmd).contains(&ln))
+            .filter_map(|c| self.line_numbers.get(&c.ln).cloned())
+            .collect();
+        lines.dedup();
+        for line in lines {
+            to_r.extend(self.find_word(line, &ln.to_string()));
+        }
+
+        return to_r;
+    }
+
+    // the line a target jumps to, or the cells a variable is kept in
+    pub fn hover(&self, pos: Pos) -> Option<String> {
+        return match self.symbol_at(pos)? {
+            Symbol::Line(ln) => {
+                let line = *self.line_numbers.get(&ln)?;
+                Some(self.lines[line].trim().to_string())
+            },
+            Symbol::Var(name) => {
+                let layout = match self.layout {
+                    Some(ref layout) => layout,
+                    None => return Some(format!("{}: variable", name))
+                };
+
+                if let Some(pos) = layout.variables.get(&name) {
+                    Some(format!("{}: number, in cell {}", name, pos))
+                } else if let Some(array) = layout.arrays.get(&name) {
+                    let size: usize = array.dims.iter().product();
+                    let dims: Vec<String> = array.dims.iter()
+                        .map(|d| d.to_string())
+                        .collect();
+                    Some(format!("{}({}): array, in cells {} to {}", name,
+                                 dims.join(", "), array.start,
+                                 array.start + size as u32 + 3))
+                } else if let Some(pos) = layout.strings.get(&name) {
+                    Some(format!("{}: string, in cells {} to {}", name, pos,
+                                 pos + STRING_CAPACITY + 4))
+                } else {
+                    Some(format!("{}: variable", name))
+                }
+            }
+        };
+    }
+
+    fn symbol_at(&self, pos: Pos) -> Option<Symbol> {
+        let line = self.lines.get(pos.line)?;
+        let chars: Vec<char> = line.chars().collect();
+        let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+        let col = char_index(&chars, pos.col);
+
+        // nothing inside a string literal refers to anything
+        let quotes = chars.iter().take(col).filter(|&&c| c == '"').count();
+        if quotes % 2 == 1 {
+            return None;
+        }
+
+        let mut start = col;
+        if start >= chars.len() || !is_word(chars[start]) {
+            // the cursor can also be just after the word
+            if start == 0 || start > chars.len() || !is_word(chars[start - 1]) {
+                return None;
+            }
+            start -= 1;
+        }
+        while start > 0 && is_word(chars[start - 1]) {
+            start -= 1;
+        }
+        let end = start + chars[start..].iter().take_while(|&&c| is_word(c)).count();
+        let word: String = chars[start..end].iter().collect();
+
+        if word.chars().all(|c| c.is_ascii_digit()) {
+            let ln = word.parse::<u32>().ok()?;
+            // the line's own number, or one of its jumps
+            let first = chars.iter().take_while(|c| c.is_whitespace()).count();
+            if start == first {
+                return Some(Symbol::Line(ln));
+            }
+            let jumps = self.cmds.iter()
+                .filter(|c| self.line_numbers.get(&c.ln) == Some(&pos.line))
+                .any(|c| targets(&c.cmd).contains(&ln));
+            if jumps && self.line_numbers.contains_key(&ln) {
+                return Some(Symbol::Line(ln));
+            }
+            return None;
+        }
+
+        let name = word.to_uppercase();
+        if !word.starts_with(|c: char| c.is_alphabetic())
+            || KEYWORDS.contains(&name.as_str()) {
+            return None;
+        }
+        return Some(Symbol::Var(name));
+    }
+
+    fn line_span(&self, line: usize) -> Span {
+        let len = self.lines.get(line).map(|l| l.encode_utf16().count()).unwrap_or(0);
+        return Span { start: Pos { line: line, col: 0 },
+                      end: Pos { line: line, col: len } };
+    }
+
+    // the line a BASIC line number is on, or the whole document
+    fn ln_span(&self, ln: Option<u32>) -> Span {
+        if let Some(&line) = ln.and_then(|ln| self.line_numbers.get(&ln)) {
+            return self.line_span(line);
+        }
+
+        let last = self.lines.len().saturating_sub(1);
+        return Span { start: Pos { line: 0, col: 0 },
+                      end: self.line_span(last).end };
+    }
+
+    fn line_number_span(&self, ln: u32) -> Option<Span> {
+        let line = *self.line_numbers.get(&ln)?;
+        return self.find_word(line, &ln.to_string()).into_iter().next();
+    }
+
+    // the places word appears on a line as a whole word, ignoring
+    // case and string literals
+    fn find_word(&self, line: usize, word: &str) -> Vec<Span> {
+        let chars: Vec<char> = self.lines[line].chars()
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let word: Vec<char> = word.chars().map(|c| c.to_ascii_uppercase()).collect();
+        let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+
+        let mut to_r = Vec::new();
+        let mut in_string = false;
+        let mut col = 0;
+        while col < chars.len() {
+            if chars[col] == '"' {
+                in_string = !in_string;
+            } else if !in_string && chars[col..].starts_with(&word)
+                && (col == 0 || !is_word(chars[col - 1]))
+                && !chars.get(col + word.len()).is_some_and(|&c| is_word(c)) {
+                let start = utf16_len(&chars[..col]);
+                let end = start + utf16_len(&word);
+                to_r.push(Span { start: Pos { line: line, col: start },
+                                 end: Pos { line: line, col: end } });
+                col += word.len();
+                continue;
+            }
+            col += 1;
+        }
+
+        return to_r;
+    }
+}
+
+fn utf16_len(chars: &[char]) -> usize {
+    return chars.iter().map(|c| c.len_utf16()).sum();
+}
+
+// the index of the char a UTF-16 column is in
+fn char_index(chars: &[char], col: usize) -> usize {
+    let mut units = 0;
+    for (idx, c) in chars.iter().enumerate() {
+        units += c.len_utf16();
+        if units > col {
+            return idx;
+        }
+    }
+    return chars.len();
+}
+
+// the line numbers a statement can jump to
+fn targets(stmt: &DBStmt) -> Vec<u32> {
+    return match *stmt {
+        DBStmt::GOTO { lineno } | DBStmt::GOSUB { lineno } => vec![lineno],
+        DBStmt::ONGOTO { ref linenos, .. }
+        | DBStmt::ONGOSUB { ref linenos, .. } => linenos.clone(),
+        DBStmt::IF { ref then_stmts, ref else_stmts, .. } => {
+            then_stmts.iter().chain(else_stmts.iter())
+                .flat_map(targets)
+                .collect()
+        },
+        _ => Vec::new()
+    };
+}
+
+// whether a statement gives name a value, or declares it
+fn assigns(stmt: &DBStmt, name: &str) -> bool {
+    let target_is = |target: &DBLetTarget| match *target {
+        DBLetTarget::VAR(ref v) => v == name,
+        DBLetTarget::ARR(ref def) => def.varname == name
+    };
+
+    return match *stmt {
+        DBStmt::LET { ref target, .. } => target_is(target),
+        DBStmt::READ { ref varnames } => varnames.iter().any(target_is),
+        DBStmt::FOR { ref varname, .. } => varname == name,
+        DBStmt::DIM { ref arrays } => arrays.iter().any(|a| a.varname == name),
+        DBStmt::DEF { ref funcname, .. } => funcname == name,
+        DBStmt::MAT { ref target, .. } => target == name,
+        DBStmt::MATREAD { ref arrays } => arrays.iter().any(|a| a == name),
+        DBStmt::IF { ref then_stmts, ref else_stmts, .. } => {
+            then_stmts.iter().chain(else_stmts.iter())
+                .any(|s| assigns(s, name))
+        },
+        _ => false
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PROGRAM: &str = "10 LET X = 1
+20 GOSUB 50
+30 IF X < 3 THEN 20
+40 END
+50 LET x = X + 1 : PRINT \"X 20\"
+60 RETURN
+";
+
+    fn at(line: usize, col: usize) -> Pos {
+        return Pos { line: line, col: col };
+    }
+
+    fn span(line: usize, start: usize, end: usize) -> Span {
+        return Span { start: at(line, start), end: at(line, end) };
+    }
+
+    #[test]
+    fn definition_test() {
+        let a = Analysis::new(PROGRAM);
+        assert!(a.diagnostics.is_empty());
+
+        // GOSUB 50 and THEN 20 go to their lines
+        assert_eq!(a.definition(at(1, 10)), Some(span(4, 0, 2)));
+        assert_eq!(a.definition(at(2, 19)), Some(span(1, 0, 2)));
+
+        // variables go to their first assignment, in any case
+        assert_eq!(a.definition(at(4, 11)), Some(span(0, 7, 8)));
+        assert_eq!(a.definition(at(2, 6)), Some(span(0, 7, 8)));
+
+        // keywords, strings and plain numbers go nowhere
+        assert_eq!(a.definition(at(1, 5)), None);
+        assert_eq!(a.definition(at(4, 29)), None);
+        assert_eq!(a.definition(at(0, 11)), None);
+    }
+
+    #[test]
+    fn references_test() {
+        let a = Analysis::new(PROGRAM);
+        assert_eq!(a.references(at(1, 0), false), vec![span(2, 17, 19)]);
+        assert_eq!(a.references(at(2, 18), true),
+                   vec![span(1, 0, 2), span(2, 17, 19)]);
+    }
+
+    #[test]
+    fn hover_test() {
+        let a = Analysis::new(PROGRAM);
+        assert_eq!(a.hover(at(0, 7)), Some(String::from("X: number, in cell 0")));
+        assert_eq!(a.hover(at(1, 9)), Some(String::from("50 LET x = X + 1 : PRINT \"X 20\"")));
+    }
+
+    #[test]
+    fn diagnostics_test() {
+        let a = Analysis::new("10 LET X = 1\n20 PRUNT X\n30 END\n");
+        assert_eq!(a.diagnostics.len(), 1);
+        assert_eq!(a.diagnostics[0].span, span(1, 0, 10));
+        assert_eq!(a.diagnostics[0].message, "Could not parse line: 20 PRUNT X");
+
+        let a = Analysis::new("10 PRINT 1\n20 NEXT I\n30 END\n");
+        assert_eq!(a.diagnostics.len(), 1);
+        assert_eq!(a.diagnostics[0].message,
+                   "NEXT statement without preceeding FOR loop!");
+
+        // an error without a line number covers the whole program
+        let a = Analysis::new("10 FOR I = 1 TO 3\n20 PRINT I\n");
+        assert_eq!(a.diagnostics.len(), 1);
+        assert_eq!(a.diagnostics[0].span,
+                   Span { start: at(0, 0), end: at(1, 10) });
+    }
+
+    #[test]
+    fn utf16_test() {
+        // the emoji is two UTF-16 code units
+        let a = Analysis::new("10 LET Y = 2\n20 PRINT \"é😀\", Y : GOTO 10\n");
+        assert!(a.diagnostics.is_empty());
+        assert_eq!(a.hover(at(1, 16)), Some(String::from("Y: number, in cell 0")));
+        assert_eq!(a.definition(at(1, 16)), Some(span(0, 7, 8)));
+        assert_eq!(a.references(at(0, 0), false), vec![span(1, 25, 27)]);
+    }
+}
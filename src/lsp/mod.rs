@@ -0,0 +1,290 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+mod analysis;
+
+use std::collections::HashMap;
+use std::io;
+use std::io::prelude::*;
+use serde_json::{self, Value};
+
+use self::analysis::{Analysis, Pos, Span};
+
+// a language server over stdin and stdout
+pub fn run() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    return serve(stdin.lock(), stdout.lock());
+}
+
+// answers messages until the client exits or input ends
+pub fn serve<R: BufRead, W: Write>(mut input: R, mut output: W) -> io::Result<()> {
+    let mut docs: HashMap<String, Analysis> = HashMap::new();
+
+    while let Some(body) = read_message(&mut input)? {
+        let msg: Value = match serde_json::from_slice(&body) {
+            Ok(msg) => msg,
+            Err(e) => {
+                // the id can't be known, so the error has none
+                write_message(&mut output, &json!({
+                    "jsonrpc": "2.0",
+                    "id": Value::Null,
+                    "error": { "code": -32700,
+                               "message": format!("Could not parse message: {}", e) }
+                }))?;
+                continue;
+            }
+        };
+
+        let method = msg["method"].as_str().unwrap_or("").to_string();
+        let params = &msg["params"];
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or("").to_string();
+
+        let result = match method.as_str() {
+            "initialize" => json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "definitionProvider": true,
+                    "referencesProvider": true,
+                    "hoverProvider": true
+                },
+                "serverInfo": { "name": "basicaf" }
+            }),
+
+            "shutdown" => Value::Null,
+            "exit" => return Ok(()),
+
+            "textDocument/didOpen" | "textDocument/didChange" => {
+                // only whole documents are synced, so the last change
+                // is the new text
+                let text = if method == "textDocument/didOpen" {
+                    params["textDocument"]["text"].as_str()
+                } else {
+                    params["contentChanges"].as_array()
+                        .and_then(|c| c.last())
+                        .and_then(|c| c["text"].as_str())
+                };
+
+                if let Some(text) = text {
+                    let analysis = Analysis::new(text);
+                    publish(&mut output, &uri, &analysis)?;
+                    docs.insert(uri, analysis);
+                }
+                continue;
+            },
+
+            "textDocument/didClose" => {
+                docs.remove(&uri);
+                write_message(&mut output, &json!({
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/publishDiagnostics",
+                    "params": { "uri": uri, "diagnostics": [] }
+                }))?;
+                continue;
+            },
+
+            "textDocument/definition" => {
+                docs.get(&uri)
+                    .and_then(|a| a.definition(pos_from(params)))
+                    .map(|span| json!({ "uri": uri, "range": range(span) }))
+                    .unwrap_or(Value::Null)
+            },
+
+            "textDocument/references" => {
+                let include_decl = params["context"]["includeDeclaration"]
+                    .as_bool().unwrap_or(false);
+                let spans = docs.get(&uri)
+                    .map(|a| a.references(pos_from(params), include_decl))
+                    .unwrap_or_default();
+                Value::Array(spans.into_iter()
+                             .map(|span| json!({ "uri": uri, "range": range(span) }))
+                             .collect())
+            },
+
+            "textDocument/hover" => {
+                docs.get(&uri)
+                    .and_then(|a| a.hover(pos_from(params)))
+                    .map(|text| json!({
+                        "contents": { "kind": "plaintext", "value": text }
+                    }))
+                    .unwrap_or(Value::Null)
+            },
+
+            _ => {
+                // notifications we don't know are ignored, requests
+                // get an error
+                if msg.get("id").is_some() {
+                    write_message(&mut output, &json!({
+                        "jsonrpc": "2.0",
+                        "id": msg["id"],
+                        "error": { "code": -32601,
+                                   "message": format!("Unknown method {}", method) }
+                    }))?;
+                }
+                continue;
+            }
+        };
+
+        write_message(&mut output, &json!({
+            "jsonrpc": "2.0",
+            "id": msg["id"],
+            "result": result
+        }))?;
+    }
+
+    return Ok(());
+}
+
+fn publish<W: Write>(output: &mut W, uri: &str, analysis: &Analysis) -> io::Result<()> {
+    let diagnostics: Vec<Value> = analysis.diagnostics.iter()
+        .map(|d| json!({
+            "range": range(d.span),
+            "severity": if d.is_warning { 2 } else { 1 },
+            "source": "basicaf",
+            "message": d.message
+        }))
+        .collect();
+
+    return write_message(output, &json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics }
+    }));
+}
+
+fn pos_from(params: &Value) -> Pos {
+    let get = |key: &str| params["position"][key].as_u64().unwrap_or(0) as usize;
+    return Pos { line: get("line"), col: get("character") };
+}
+
+fn range(span: Span) -> Value {
+    return json!({
+        "start": { "line": span.start.line, "character": span.start.col },
+        "end": { "line": span.end.line, "character": span.end.col }
+    });
+}
+
+// reads the body of one message, which is a Content-Length header,
+// a blank line and then that many bytes of JSON
+fn read_message<R: BufRead>(input: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim();
+        if header.is_empty() {
+            if length.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        let mut parts = header.splitn(2, ':');
+        let name = parts.next().unwrap_or("");
+        if name.eq_ignore_ascii_case("Content-Length") {
+            length = parts.next().and_then(|v| v.trim().parse::<usize>().ok());
+        }
+    }
+
+    let mut body = vec![0; length.unwrap()];
+    input.read_exact(&mut body)?;
+    return Ok(Some(body));
+}
+
+fn write_message<W: Write>(output: &mut W, msg: &Value) -> io::Result<()> {
+    let body = msg.to_string();
+    write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    return output.flush();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame(msg: Value) -> String {
+        let body = msg.to_string();
+        return format!("Content-Length: {}\r\n\r\n{}", body.len(), body);
+    }
+
+    #[test]
+    fn serve_test() {
+        let uri = "file:///test.db";
+        let mut input = String::new();
+        input.push_str(&frame(json!({
+            "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}
+        })));
+        input.push_str(&frame(json!({
+            "jsonrpc": "2.0", "method": "textDocument/didOpen",
+            "params": { "textDocument": {
+                "uri": uri, "languageId": "basic", "version": 1,
+                "text": "10 LET X = 1\n20 GOTO 10\n"
+            }}
+        })));
+        input.push_str(&frame(json!({
+            "jsonrpc": "2.0", "id": 2, "method": "textDocument/definition",
+            "params": { "textDocument": { "uri": uri },
+                        "position": { "line": 1, "character": 8 } }
+        })));
+        input.push_str(&frame(json!({
+            "jsonrpc": "2.0", "method": "textDocument/didChange",
+            "params": { "textDocument": { "uri": uri, "version": 2 },
+                        "contentChanges": [{ "text": "10 LET X = \n" }] }
+        })));
+        input.push_str(&frame(json!({
+            "jsonrpc": "2.0", "id": 3, "method": "workspace/symbol",
+            "params": {}
+        })));
+        input.push_str("Content-Length: 9\r\n\r\n{\"id\": 4,");
+        input.push_str(&frame(json!({ "jsonrpc": "2.0", "method": "exit" })));
+
+        let mut output = Vec::new();
+        serve(input.as_bytes(), &mut output).unwrap();
+
+        let mut reader = io::Cursor::new(output);
+        let mut msgs: Vec<Value> = Vec::new();
+        while let Some(body) = read_message(&mut reader).unwrap() {
+            msgs.push(serde_json::from_slice(&body).unwrap());
+        }
+        assert_eq!(msgs.len(), 6);
+
+        assert_eq!(msgs[0]["id"], 1);
+        assert_eq!(msgs[0]["result"]["capabilities"]["definitionProvider"], true);
+
+        assert_eq!(msgs[1]["method"], "textDocument/publishDiagnostics");
+        assert_eq!(msgs[1]["params"]["diagnostics"], json!([]));
+
+        assert_eq!(msgs[2]["id"], 2);
+        assert_eq!(msgs[2]["result"]["range"]["start"],
+                   json!({ "line": 0, "character": 0 }));
+
+        let diagnostics = &msgs[3]["params"]["diagnostics"];
+        assert_eq!(diagnostics[0]["message"], "Could not parse line: 10 LET X =");
+        assert_eq!(diagnostics[0]["range"]["start"]["line"], 0);
+
+        assert_eq!(msgs[4]["id"], 3);
+        assert_eq!(msgs[4]["error"]["code"], -32601);
+
+        // a message that isn't JSON is answered, and serving goes on
+        assert_eq!(msgs[5]["id"], Value::Null);
+        assert_eq!(msgs[5]["error"]["code"], -32700);
+    }
+}
@@ -25,6 +25,11 @@ extern crate clap;
 #[macro_use]
 extern crate nom;
 extern crate unescape;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
 
 mod parser;
 mod codegen;
@@ -32,17 +37,100 @@ mod ir;
 mod interp;
 mod optimizer;
 mod compile;
+mod lsp;
+mod repl;
 
 use std::fs::File;
+use std::io;
+use std::process;
 use std::io::prelude::*;
 
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg, ArgMatches, ErrorKind, SubCommand};
+
+// the flags that change how a program is compiled, shared by the
+// compiler and the run command
+fn compile_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    return vec![
+        Arg::with_name("disable-opt")
+            .long("disable-opt")
+            .short("d")
+            .help("Disables the optimizer"),
+        Arg::with_name("decimals")
+            .conflicts_with("graphviz")
+            .long("decimals")
+            .takes_value(true)
+            .value_name("places")
+            .help("Use fixed-point numbers with the given number of decimal places (at most 9)"),
+        Arg::with_name("check-bounds")
+            .conflicts_with("graphviz")
+            .long("check-bounds")
+            .help("Stop with an error when an array index is out of range"),
+        Arg::with_name("check-arithmetic")
+            .conflicts_with("graphviz")
+            .long("check-arithmetic")
+            .help("Stop with an error on division by zero or a subtraction with a negative result"),
+        Arg::with_name("classic-print")
+            .conflicts_with("graphviz")
+            .long("classic-print")
+            .help("PRINT like classic BASIC: ; joins items, , moves to the next 15 character zone, and a newline is added unless the statement ends with ; or ,"),
+        Arg::with_name("strict")
+            .conflicts_with("graphviz")
+            .long("strict")
+            .help("Stop with an error on lines that are never reached, instead of leaving them out with a warning")
+    ];
+}
+
+fn get_options(matches: &ArgMatches) -> ir::Options {
+    let decimals = matches.value_of("decimals")
+        .map(|d| d.parse::<u32>().expect("decimals must be a whole number"))
+        .unwrap_or(0);
+
+    if decimals > 9 {
+        panic!("At most 9 decimal places are supported");
+    }
+
+    return ir::Options {
+        decimals: decimals,
+        check_bounds: matches.is_present("check-bounds"),
+        check_arithmetic: matches.is_present("check-arithmetic"),
+        classic_print: matches.is_present("classic-print"),
+        strict: matches.is_present("strict")
+    };
+}
+
+// reads the input file, or stdin for -
+fn read_input(path: &str) -> String {
+    let mut contents = String::new();
+    if path == "-" {
+        io::stdin().read_to_string(&mut contents)
+            .expect("something went wrong reading stdin!");
+        return contents;
+    }
+
+    let mut f = File::open(path).expect("file not found");
+    f.read_to_string(&mut contents)
+        .expect("something went wrong reading the file!");
+    return contents;
+}
+
+// runs a Brainf**k program with stdin and stdout, printing its
+// output as it goes
+fn execute(source: String) {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut interp = interp::BFEnv::new();
+    interp.run(source, &mut stdin.lock(), &mut out)
+        .and_then(|_| writeln!(out))
+        .expect("something went wrong writing the output!");
+}
 
 fn main() {
     let matches = App::new("BASICAF")
         .version("0.1.2")
         .author("Ryan Marcus <ryan@ryanmarc.us>")
         .about("A BASIC to Brainf**k compiler, https://github.com/RyanMarcus/basicaf")
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(Arg::with_name("semcom")
              .long("semantic-comments")
              .short("s")
@@ -58,49 +146,215 @@ fn main() {
              .long("graphviz")
              .short("g")
              .help("Output a graphviz representation of the flow control of the input program, instead of compiling it"))
-        .arg(Arg::with_name("disable-opt")
-             .long("disable-opt")
-             .short("d")
-             .help("Disables the optimizer"))
+        .arg(Arg::with_name("json")
+             .conflicts_with("graphviz")
+             .conflicts_with("emit")
+             .long("json")
+             .help("Output the parsed program, its blocks and the cells of its variables as JSON instead of compiling it (see JSON.md)"))
+        .arg(Arg::with_name("dominators")
+             .requires("graphviz")
+             .long("dominators")
+             .help("With --graphviz, output the dominator tree of the program's blocks instead"))
+        .args(&compile_args())
+        .arg(Arg::with_name("emit")
+             .conflicts_with("graphviz")
+             .long("emit")
+             .takes_value(true)
+             .multiple(true)
+             .number_of_values(1)
+             .require_delimiter(true)
+             .possible_values(&compile::STAGES)
+             .value_name("stage")
+             .help("Output the program at the given stages of compiling instead, like --emit=ast,ir"))
         .arg(Arg::with_name("input file")
-             .help("The input BASIC file")
+             .help("The input BASIC file, or - to read it from stdin")
              .takes_value(true)
              .required(true))
+        .arg(Arg::with_name("output")
+             .conflicts_with("execute")
+             .short("o")
+             .long("output")
+             .takes_value(true)
+             .value_name("file")
+             .help("Write the output to a file instead of stdout"))
         .arg(Arg::with_name("execute")
              .conflicts_with("graphviz")
+             .conflicts_with("emit")
+             .conflicts_with("json")
              .conflicts_with("semcom")
              .conflicts_with("ircom")
              .conflicts_with("disable-opt")
              .short("e")
              .long("execute")
              .help("Executes the input file as a Brainf**k program"))
+        .subcommand(SubCommand::with_name("run")
+                    .about("Compiles a BASIC program and runs it, with stdin as its input")
+                    .args(&compile_args())
+                    .arg(Arg::with_name("input file")
+                         .help("The input BASIC file, or - to read it from stdin")
+                         .required(true)))
+        .subcommand(SubCommand::with_name("renum")
+                    .about("Renumbers the lines of a BASIC program, changing its GOTO, GOSUB, IF and ON targets to match")
+                    .arg(Arg::with_name("start")
+                         .long("start")
+                         .takes_value(true)
+                         .value_name("line")
+                         .default_value("10")
+                         .help("The number of the first line"))
+                    .arg(Arg::with_name("step")
+                         .long("step")
+                         .takes_value(true)
+                         .value_name("n")
+                         .default_value("10")
+                         .help("How much each line number is after the last"))
+                    .arg(Arg::with_name("input file")
+                         .help("The input BASIC file, or - to read it from stdin")
+                         .required(true)))
+        .subcommand(SubCommand::with_name("check")
+                    .about("Warns about likely mistakes in a BASIC program, like variables read before they are set or lines that are never reached, and fails if there are any")
+                    .arg(Arg::with_name("input file")
+                         .help("The input BASIC file, or - to read it from stdin")
+                         .required(true)))
+        .subcommand(SubCommand::with_name("fmt")
+                    .about("Formats BASIC programs: upper case keywords, single spaces and parentheses where they are needed or make the grouping clear")
+                    .arg(Arg::with_name("check")
+                         .long("check")
+                         .help("Print the files that aren't formatted and fail if there are any, instead of formatting them"))
+                    .arg(Arg::with_name("write")
+                         .conflicts_with("check")
+                         .long("write")
+                         .short("w")
+                         .help("Format the files in place instead of printing them"))
+                    .arg(Arg::with_name("input file")
+                         .help("The input BASIC files, or - to read one from stdin")
+                         .multiple(true)
+                         .required(true)))
+        .subcommand(SubCommand::with_name("repl")
+                    .about("Edits and runs a BASIC program interactively: enter numbered lines, then LIST, RUN, SAVE file, LOAD file, NEW or BYE")
+                    .args(&compile_args()))
+        .subcommand(SubCommand::with_name("lsp")
+                    .about("Runs a language server for BASIC programs over stdin and stdout"))
         .get_matches();
 
+    // the compile flags of a subcommand come after it, and would be
+    // ignored before it
+    if let Some(sub) = matches.subcommand_name() {
+        let args = compile_args();
+        if let Some(arg) = args.iter().find(|a| matches.is_present(a.b.name)) {
+            clap::Error::with_description(
+                &format!("--{} can't come before the {} subcommand", arg.b.name, sub),
+                ErrorKind::ArgumentConflict).exit();
+        }
+    }
+
+    if let Some(renum) = matches.subcommand_matches("renum") {
+        let contents = read_input(renum.value_of("input file").unwrap());
+        let start = renum.value_of("start").unwrap().parse::<u32>()
+            .expect("start must be a whole number");
+        let step = renum.value_of("step").unwrap().parse::<u32>()
+            .expect("step must be a whole number");
+        print!("{}", compile::renumber(contents, start, step));
+        return;
+    }
+
+    if let Some(check) = matches.subcommand_matches("check") {
+        let contents = read_input(check.value_of("input file").unwrap());
+        let warnings = compile::check(contents);
+        for warning in warnings.iter() {
+            println!("warning: {}", warning);
+        }
+
+        if !warnings.is_empty() {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(fmt) = matches.subcommand_matches("fmt") {
+        let mut failed = false;
+        for path in fmt.values_of("input file").unwrap() {
+            let contents = read_input(path);
+            let formatted = match compile::format_program(contents.clone()) {
+                Ok(formatted) => formatted,
+                Err(e) => {
+                    eprintln!("{}: {}", path, e);
+                    failed = true;
+                    continue;
+                }
+            };
+
+            if fmt.is_present("check") {
+                if formatted != contents {
+                    println!("{} is not formatted", path);
+                    failed = true;
+                }
+            } else if fmt.is_present("write") && path != "-" {
+                if formatted != contents {
+                    let mut f = File::create(path).expect("could not write the file");
+                    f.write_all(formatted.as_bytes())
+                        .expect("something went wrong writing the file!");
+                }
+            } else {
+                print!("{}", formatted);
+            }
+        }
+
+        if failed {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(repl) = matches.subcommand_matches("repl") {
+        repl::run(!repl.is_present("disable-opt"), &get_options(repl))
+            .expect("something went wrong talking to the terminal!");
+        return;
+    }
+
+    if matches.subcommand_matches("lsp").is_some() {
+        lsp::run().expect("something went wrong talking to the client!");
+        return;
+    }
+
+    if let Some(run) = matches.subcommand_matches("run") {
+        let contents = read_input(run.value_of("input file").unwrap());
+        let bf = compile::compile_with(contents, false, false,
+                                       !run.is_present("disable-opt"),
+                                       &get_options(run));
+        execute(bf);
+        return;
+    }
+
     let sem_comments = matches.is_present("semcom");
     let ir_comments = matches.is_present("ircom");
     let no_opt = matches.is_present("disable-opt");
     let gv = matches.is_present("graphviz");
-    let execute = matches.is_present("execute");
-
-    let inp_file = matches.value_of("input file").unwrap();
+    let options = get_options(&matches);
 
-    let mut f = File::open(inp_file).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("something went wrong reading the file!");
+    let contents = read_input(matches.value_of("input file").unwrap());
 
-    if execute {
-        let mut interp = interp::BFEnv::new();
-        let result = interp.execute(contents);
-        println!("{}", result);
+    if matches.is_present("execute") {
+        execute(contents);
         return;
     }
 
     let s = if gv {
-        compile::to_graphviz(contents)
+        compile::to_graphviz(contents, matches.is_present("dominators"))
+    } else if matches.is_present("json") {
+        compile::to_json(contents, !no_opt, &options)
+    } else if let Some(stages) = matches.values_of("emit") {
+        compile::emit_stages(contents, sem_comments, ir_comments, !no_opt,
+                             &options, &stages.collect::<Vec<&str>>())
     } else {
-        compile::compile(contents, sem_comments, ir_comments, !no_opt)
+        compile::compile_with(contents, sem_comments, ir_comments, !no_opt,
+                              &options)
     };
 
-    println!("{}", s);
+    match matches.value_of("output") {
+        Some(path) => {
+            let mut f = File::create(path).expect("could not create the output file");
+            writeln!(f, "{}", s).expect("something went wrong writing the file!");
+        },
+        None => println!("{}", s)
+    }
 }
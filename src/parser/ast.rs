@@ -20,18 +20,37 @@
  
 use parser::structs::DBArrayDef;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum OpCode {
     Add, Sub, Times, Div
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Expr {
     O(Box<Expr>, OpCode, Box<Expr>),
     N(i32),
+    D(f64),
     V(String),
     A(DBArrayDef),
-    E(Box<Expr>)
+    E(Box<Expr>),
+    S(String),
+    F(String, Vec<Expr>)
+}
+
+impl Expr {
+    // string expressions are built from string literals, string
+    // variables and string functions, whose names end in a $
+    pub fn is_string(&self) -> bool {
+        return match *self {
+            Expr::O(ref e1, _, ref e2) => e1.is_string() || e2.is_string(),
+            Expr::V(ref name) => name.ends_with('$'),
+            Expr::A(ref def) => def.varname.ends_with('$'),
+            Expr::E(ref e) => e.is_string(),
+            Expr::S(_) => true,
+            Expr::F(ref name, _) => name.ends_with('$'),
+            Expr::N(_) | Expr::D(_) => false
+        };
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -21,4 +21,5 @@
 pub mod structs;
 mod parse;
 pub mod ast;
-pub use self::parse::parse_bytes;
+pub mod print;
+pub use self::parse::{parse_bytes, try_parse_bytes};
@@ -17,14 +17,27 @@
 // along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
 // 
 // < end copyright > 
-use nom::{digit,not_line_ending,line_ending,alphanumeric, IResult};
+use nom::{digit,not_line_ending,line_ending,alpha, IResult};
+use std::fmt;
 use std::str;
 use std::str::FromStr;
 
-use parser::structs::{DBCommand, DBArrayDef, DBStmt, DBExpr, DBLetTarget};
+use parser::structs::{DBCommand, DBArrayDef, DBStmt, DBExpr, DBLetTarget, DBData, DBMatExpr,
+                      DBPrintSep};
 use parser::ast::{Expr, OpCode};
 use unescape::unescape;
 
+// eats spaces and tabs, but not line endings, which separate commands
+named!(space, eat_separator!(&b" \t"[..]));
+
+// like ws!, but using the space parser above
+macro_rules! sp (
+    ($i:expr, $($args:tt)*) => (
+        {
+            sep!($i, space, $($args)*)
+        }
+    )
+);
 
 // parses a >= 0 integer value, like a line number
 named!(line_number<&[u8], u32>,
@@ -40,10 +53,46 @@ named!(db_float<&[u8], f32>,
            FromStr::from_str)
        );
 
+// parses a number with a decimal point, like 2.75
+named!(db_decimal<&[u8], f64>,
+       map_res!(
+           map_res!(recognize!(do_parse!(
+               digit >> tag!(".") >> digit >> ()
+           )), str::from_utf8),
+           FromStr::from_str)
+       );
+
+fn is_name_char(c: u8) -> bool {
+    return c.is_ascii_alphanumeric() || c == b'_';
+}
 
-// parses a function or variable name
+// parses a function or variable name, which starts with a letter
+// and can have digits and underscores, like TOTAL_2. Names of
+// string variables end in a $, like A$. Names are not case
+// sensitive, so they are kept in upper case.
 named!(db_name<&[u8], String>,
-       map!(map_res!(alphanumeric, str::from_utf8), String::from)
+       map!(map_res!(recognize!(do_parse!(
+           alpha
+               >> take_while!(is_name_char)
+               >> opt!(complete!(tag!("$")))
+               >> ()
+               )), str::from_utf8), |x: &str| x.to_uppercase())
+       );
+
+// the text of a string literal with its escapes replaced, or None
+// if it has a bad escape, like a \ at the end
+fn unescape_bytes(txt: Option<&[u8]>) -> Option<String> {
+    return str::from_utf8(txt.unwrap_or(&b""[..])).ok().and_then(unescape);
+}
+
+// parses a quoted string, like "hello"
+named!(db_string<&[u8], String>,
+       do_parse!(
+           tag!("\"")
+               >> txt: map_opt!(opt!(is_not_s!("\"\n\r")), unescape_bytes)
+               >> tag!("\"")
+               >> (txt)
+               )
        );
 
 // parses valid ops for if statements
@@ -66,12 +115,37 @@ named!(db_op<&[u8], OpCode>,
             )
        );
 
+// parses a call to a builtin function, like MID$(A$, 2, 3)
+named!(db_builtin<&[u8], Expr>,
+       do_parse!(
+           name: map_res!(alt!(tag_no_case!("LEN")    |
+                               tag_no_case!("MID$")   |
+                               tag_no_case!("LEFT$")  |
+                               tag_no_case!("RIGHT$") |
+                               tag_no_case!("CHR$")   |
+                               tag_no_case!("ASC")    |
+                               tag_no_case!("STR$")   |
+                               tag_no_case!("VAL")    |
+                               tag_no_case!("TAB")    |
+                               tag_no_case!("SPC")),
+                          str::from_utf8)
+               >> ws!(tag!("("))
+               >> args: separated_nonempty_list!(ws!(tag!(",")),
+                                                 ast_expr)
+               >> tag!(")")
+               >> (Expr::F(name.to_uppercase(), args))
+               )
+       );
+
 named!(ast_term<&[u8], Expr>,
        alt!(
            complete!(delimited!(tag!("("), ast_expr, tag!(")")))
                => {|x| Expr::E(Box::new(x))} |
            
+           complete!(db_string) => { |x| Expr::S(x) } |
+           complete!(db_builtin) |
            complete!(db_array_dim) => { |x| Expr::A(x) } |
+           complete!(db_decimal) => { |x| Expr::D(x) } |
            complete!(line_number) => { |x| Expr::N(x as i32) } |
            complete!(db_name) => { |x| Expr::V(x) }
            )
@@ -86,7 +160,7 @@ named!(ast_expr<&[u8], Expr>,
                    >> ( Expr::O(Box::new(expr1), op, Box::new(expr2)) )
                    )) |
            do_parse!(
-               many0!(tag!(" "))
+               space
                    >> term: ast_term
                    >> (term)
                    )
@@ -95,20 +169,14 @@ named!(ast_expr<&[u8], Expr>,
 
 // parses an expression, which can either be a quoted string
 // or an arithmetic sequence with functions, but no rel ops.
+// Strings can be joined with +, like A$ + "!"
 named!(db_expr<&[u8], DBExpr>,
-       alt!(
-           do_parse!(
-               many0!(tag!(" "))
-                   >> tag!("\"")
-                   >> txt: is_not_s!("\"\n\r")
-                   >> tag!("\"")
-                   >>( DBExpr::S(unescape(str::from_utf8(txt).unwrap()).unwrap()) )
-                   )
-               |
-           do_parse!(
-               expr: ast_expr
-                   >> ( DBExpr::E(expr)  )
-                   )
+       do_parse!(
+           expr: ast_expr
+               >> ( match expr {
+                   Expr::S(txt) => DBExpr::S(txt),
+                   expr => DBExpr::E(expr)
+               } )
                )
        );
              
@@ -118,8 +186,8 @@ named!(db_array_dim<&[u8], DBArrayDef>,
        do_parse!(
            name: ws!(db_name)
                >> tag!("(")
-               >> dims: separated_nonempty_list!(ws!(tag!(",")),
-                                                 ast_expr)
+               >> dims: separated_nonempty_list_complete!(ws!(tag!(",")),
+                                                          ast_expr)
                                                  
                >> tag!(")")
                >> (DBArrayDef { varname: name,
@@ -132,14 +200,13 @@ named!(db_array_dim<&[u8], DBArrayDef>,
 // parses a def statement, like DEF f(X) = 5 * X
 named!(db_def_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("DEF")
+           tag_no_case!("DEF")
                >> fname: ws!(db_name)
                >> ws!(tag!("("))
                >> vname: db_name
                >> ws!(tag!(")"))
                >> ws!(tag!("="))
                >> expr: db_expr
-               >> line_ending
                >> ( DBStmt::DEF{funcname: fname,
                                 varname: vname,
                                 expr: expr} )
@@ -150,9 +217,8 @@ named!(db_def_stmt<&[u8], DBStmt>,
 // parses a dim statement, like DIM F(5, 2), D(4)
 named!(db_dim_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("DIM")
-               >> dims: many1!(db_array_dim)
-               >> line_ending
+           tag_no_case!("DIM")
+               >> dims: many1!(complete!(db_array_dim))
                >> ( DBStmt::DIM { arrays: dims } )
                )
        );
@@ -160,8 +226,7 @@ named!(db_dim_stmt<&[u8], DBStmt>,
 // parses an end statement, like END
 named!(db_end_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("END")
-               >> line_ending
+           tag_no_case!("END")
                >> (DBStmt::END)
                )
        );
@@ -169,27 +234,25 @@ named!(db_end_stmt<&[u8], DBStmt>,
 // parses a stop statement, like STOP
 named!(db_stop_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("STOP")
-               >> line_ending
-               >> (DBStmt::END)
+           tag_no_case!("STOP")
+               >> (DBStmt::STOP)
                )
        );
 
 // parses a for statement, like FOR x = 5 TO y STEP 2
 named!(db_for_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("FOR"))
+           ws!(tag_no_case!("FOR"))
                >> var: db_name
                >> ws!(tag!("="))
                >> from: db_expr
-               >> ws!(tag!("TO"))
+               >> ws!(tag_no_case!("TO"))
                >> to: db_expr
                >> step: opt!(do_parse!(
-                   tag!("STEP")
+                   tag_no_case!("STEP")
                              >> to_r: ws!(db_expr)
                              >> ( to_r )
                              ))
-               >> line_ending
                >> (DBStmt::FOR{ varname: var,
                                 from_expr: from,
                                 to_expr: to,
@@ -200,20 +263,63 @@ named!(db_for_stmt<&[u8], DBStmt>,
 // parses a next statement, like NEXT
 named!(db_next_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("NEXT"))
+           ws!(tag_no_case!("NEXT"))
                >> var: db_name
-               >> line_ending
                >> (DBStmt::NEXT{ varname: var })
                )
        );
 
 
+// parses a while statement, like WHILE x < 5
+named!(db_while_stmt<&[u8], DBStmt>,
+       do_parse!(
+           ws!(tag_no_case!("WHILE"))
+               >> expr1: db_expr
+               >> op: ws!(db_rela_op)
+               >> expr2: db_expr
+               >> (DBStmt::WHILE{ expr1: expr1,
+                                  op: String::from(op),
+                                  expr2: expr2 })
+               )
+       );
+
+// parses a wend statement, like WEND
+named!(db_wend_stmt<&[u8], DBStmt>,
+       do_parse!(
+           tag_no_case!("WEND")
+               >> (DBStmt::WEND)
+               )
+       );
+
+// parses a do statement, like DO
+named!(db_do_stmt<&[u8], DBStmt>,
+       do_parse!(
+           tag_no_case!("DO")
+               >> (DBStmt::DO)
+               )
+       );
+
+// parses a loop statement, like LOOP UNTIL x = 5 or LOOP WHILE x < 5
+named!(db_loop_stmt<&[u8], DBStmt>,
+       do_parse!(
+           ws!(tag_no_case!("LOOP"))
+               >> until: ws!(alt!(tag_no_case!("UNTIL") => { |_| true } |
+                                  tag_no_case!("WHILE") => { |_| false }))
+               >> expr1: db_expr
+               >> op: ws!(db_rela_op)
+               >> expr2: db_expr
+               >> (DBStmt::LOOP{ until: until,
+                                 expr1: expr1,
+                                 op: String::from(op),
+                                 expr2: expr2 })
+               )
+       );
+
 // parses a GOSUB statement, like GOSUB 15
 named!(db_gosub_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("GOSUB"))
+           ws!(tag_no_case!("GOSUB"))
                >> lno: line_number
-               >> line_ending
                >> (DBStmt::GOSUB{ lineno: lno })
                )
        );
@@ -221,8 +327,7 @@ named!(db_gosub_stmt<&[u8], DBStmt>,
 // parses a return statement, like RETURN
 named!(db_return_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("RETURN")
-               >> line_ending
+           tag_no_case!("RETURN")
                >> (DBStmt::RETURN)
                )
        );
@@ -230,61 +335,143 @@ named!(db_return_stmt<&[u8], DBStmt>,
 // parses a GOTO statement, like GOTO 15
 named!(db_goto_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("GOTO"))
+           ws!(tag_no_case!("GOTO"))
                >> lno: line_number
-               >> line_ending
                >> (DBStmt::GOTO{ lineno: lno })
                )
        );
 
-// parses an if statement, like IF x = 5 THEN 27
+// parses a computed jump, like ON x GOTO 10, 20, 30
+// or ON x GOSUB 100, 200
+named!(db_on_stmt<&[u8], DBStmt>,
+       do_parse!(
+           ws!(tag_no_case!("ON"))
+               >> expr: db_expr
+               >> gosub: ws!(alt!(tag_no_case!("GOTO") => { |_| false } |
+                                  tag_no_case!("GOSUB") => { |_| true }))
+               >> lnos: separated_nonempty_list_complete!(ws!(tag!(",")),
+                                                          line_number)
+               >> (if gosub {
+                   DBStmt::ONGOSUB { expr: expr, linenos: lnos }
+               } else {
+                   DBStmt::ONGOTO { expr: expr, linenos: lnos }
+               })
+               )
+       );
+
+// parses the branch of an if statement, which is either a line
+// number to jump to, like 27, or statements, like PRINT "x" : GOTO 5.
+// A line number is treated as a GOTO.
+named!(db_if_branch<&[u8], Vec<DBStmt> >,
+       alt!(
+           complete!(line_number) => { |x| vec![DBStmt::GOTO{ lineno: x }] } |
+           db_stmts
+           )
+       );
+
+// parses an if statement, like IF x = 5 THEN 27,
+// IF x = 5 THEN PRINT "five" or IF x = 5 THEN LET y = 1 ELSE LET y = 2
 named!(db_if_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("IF"))
+           ws!(tag_no_case!("IF"))
                >> expr1: db_expr
                >> op: ws!(db_rela_op)
                >> expr2: db_expr
-               >> ws!(tag!("THEN"))
-               >> lno: line_number
-               >> line_ending
+               >> sp!(tag_no_case!("THEN"))
+               >> then_stmts: db_if_branch
+               >> else_stmts: opt!(complete!(do_parse!(
+                   sp!(tag_no_case!("ELSE"))
+                       >> stmts: db_if_branch
+                       >> (stmts)
+                       )))
                >> (DBStmt::IF{ expr1: expr1,
                                op: String::from(op),
                                expr2: expr2,
-                               lineno: lno })
+                               then_stmts: then_stmts,
+                               else_stmts: else_stmts.unwrap_or_default() })
                )
        );
 
 // parses a let statement, like LET x = 5 * 5 or LET X(5) = 4
 named!(db_let_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("LET"))
+           ws!(tag_no_case!("LET"))
                >> varname: let_target
                >> ws!(tag!("="))
                >> expr: db_expr
-               >> line_ending
                >> (DBStmt::LET{ target: varname,
                                 expr: expr })
                )
        );
 
+// parses the separator between PRINT items, a comma or semicolon
+named!(db_print_sep<&[u8], DBPrintSep>,
+       sp!(alt!(tag!(",") => { |_| DBPrintSep::Comma } |
+                tag!(";") => { |_| DBPrintSep::Semicolon }))
+       );
+
+// parses the items of a print statement and the separators after
+// them, which can include one at the end, like x; " is x";
+named!(db_print_items<&[u8], (Vec<DBExpr>, Vec<DBPrintSep>)>,
+       do_parse!(
+           first: db_expr
+               >> rest: many0!(complete!(pair!(db_print_sep, db_expr)))
+               >> last: opt!(complete!(db_print_sep))
+               >> ({
+                   let mut seq = vec![first];
+                   let mut seps = Vec::new();
+                   for (sep, expr) in rest {
+                       seps.push(sep);
+                       seq.push(expr);
+                   }
+                   seps.extend(last);
+                   (seq, seps)
+               })
+               )
+       );
+
 // parses a print statement, like PRINT x, "is the value of x"
+// or just PRINT
 named!(db_print_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("PRINT")
-               >> exprs: separated_list_complete!(ws!(tag!(",")),
-                                                  db_expr)
-               >> dbg_dmp!(line_ending)
-               >> (DBStmt::PRINT { seq: exprs })
+           tag_no_case!("PRINT")
+               >> items: opt!(complete!(db_print_items))
+               >> ({
+                   let (seq, seps) = items.unwrap_or_default();
+                   DBStmt::PRINT { seq: seq, seps: seps }
+               })
                )
        );
 
-// parses a data statement, like DATA 5, 6, 2.0
+// parses a formatted print statement, like PRINT USING "##.#"; x
+named!(db_print_using_stmt<&[u8], DBStmt>,
+       do_parse!(
+           tag_no_case!("PRINT")
+               >> ws!(tag_no_case!("USING"))
+               >> format: db_string
+               >> items: opt!(complete!(preceded!(db_print_sep,
+                                                  db_print_items)))
+               >> ({
+                   let (seq, seps) = items.unwrap_or_default();
+                   DBStmt::PRINTUSING { format: format, seq: seq, seps: seps }
+               })
+               )
+       );
+
+// parses a single DATA item, like 2.0 or "hello"
+named!(db_data_item<&[u8], DBData>,
+       alt!(
+           db_string => { |x| DBData::S(x) } |
+           db_float => { |x| DBData::N(x) }
+           )
+       );
+
+// parses a data statement, like DATA 5, 6, 2.0, "hello"
 named!(db_data_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("DATA"))
+           ws!(tag_no_case!("DATA"))
                >> data: separated_list_complete!(ws!(tag!(",")),
-                                                 db_float)
-               >> line_ending
+                                                 db_data_item)
                >> (DBStmt::DATA { seq: data })
                )
        );
@@ -300,23 +487,72 @@ named!(let_target<&[u8], DBLetTarget>,
 // or READ x(1, 2), y(3, 4), z
 named!(db_read_stmt<&[u8], DBStmt>,
        do_parse!(
-           ws!(tag!("READ"))
-               >> vars: separated_nonempty_list!(ws!(tag!(",")),
-                                                 let_target)
-               >> line_ending
+           ws!(tag_no_case!("READ"))
+               >> vars: separated_nonempty_list_complete!(ws!(tag!(",")),
+                                                          let_target)
                >> (DBStmt::READ {
                    varnames: vars
                    })
                )
        );
 
+// parses a MAT READ statement, like MAT READ A, B
+named!(db_mat_read_stmt<&[u8], DBStmt>,
+       do_parse!(
+           ws!(tag_no_case!("MAT"))
+               >> ws!(tag_no_case!("READ"))
+               >> arrays: separated_nonempty_list_complete!(ws!(tag!(",")), db_name)
+               >> (DBStmt::MATREAD { arrays: arrays })
+               )
+       );
+
+// parses a MAT PRINT statement, like MAT PRINT A, B
+named!(db_mat_print_stmt<&[u8], DBStmt>,
+       do_parse!(
+           ws!(tag_no_case!("MAT"))
+               >> ws!(tag_no_case!("PRINT"))
+               >> arrays: separated_nonempty_list_complete!(ws!(tag!(",")), db_name)
+               >> (DBStmt::MATPRINT { arrays: arrays })
+               )
+       );
+
+// parses the right side of a MAT assignment, like ZER or B * C
+named!(db_mat_expr<&[u8], DBMatExpr>,
+       alt!(
+           complete!(tag_no_case!("ZER")) => { |_| DBMatExpr::ZER } |
+           complete!(tag_no_case!("CON")) => { |_| DBMatExpr::CON } |
+           complete!(tag_no_case!("IDN")) => { |_| DBMatExpr::IDN } |
+           complete!(do_parse!(
+               a: db_name
+                   >> op: ws!(alt!(tag!("+") | tag!("-") | tag!("*")))
+                   >> b: db_name
+                   >> (match op {
+                       b"+" => DBMatExpr::ADD(a, b),
+                       b"-" => DBMatExpr::SUB(a, b),
+                       _ => DBMatExpr::MUL(a, b)
+                   })
+                   )) |
+           complete!(db_name) => { |x| DBMatExpr::COPY(x) }
+           )
+       );
+
+// parses a MAT assignment, like MAT A = B + C or MAT A = IDN
+named!(db_mat_stmt<&[u8], DBStmt>,
+       do_parse!(
+           ws!(tag_no_case!("MAT"))
+               >> target: db_name
+               >> ws!(tag!("="))
+               >> expr: db_mat_expr
+               >> (DBStmt::MAT { target: target, expr: expr })
+               )
+       );
+
 // parses a rem statement (commment), like REM this is a comment
 named!(db_rem_stmt<&[u8], DBStmt>,
        do_parse!(
-           tag!("REM")
-               >> not_line_ending
-               >> line_ending
-               >> ( DBStmt::REM )
+           tag_no_case!("REM")
+               >> text: map_res!(not_line_ending, str::from_utf8)
+               >> ( DBStmt::REM { text: String::from(text) } )
                )
        );
                                         
@@ -324,66 +560,112 @@ named!(db_rem_stmt<&[u8], DBStmt>,
 // parses any statement
 named!(db_stmt<&[u8], DBStmt>,
        complete!(
-           ws!(alt!(db_def_stmt    |
+           sp!(alt!(db_def_stmt    |
                     db_dim_stmt    |
                     db_end_stmt    |
                     db_stop_stmt   |
                     db_for_stmt    |
                     db_next_stmt   |
+                    db_while_stmt  |
+                    db_wend_stmt   |
+                    db_do_stmt     |
+                    db_loop_stmt   |
                     db_gosub_stmt  |
                     db_return_stmt |
                     db_goto_stmt   |
+                    db_on_stmt     |
                     db_if_stmt     |
                     db_let_stmt    |
+                    db_print_using_stmt |
                     db_print_stmt  |
                     db_data_stmt   |
                     db_read_stmt   |
+                    db_mat_read_stmt  |
+                    db_mat_print_stmt |
+                    db_mat_stmt    |
                     db_rem_stmt))
                )
        );
 
 
-// parses any command (a line number and a statement)
-named!(db_command<&[u8], DBCommand>,
-       dbg_dmp!(do_parse!(
-           lnp: line_number
-               >> cmdp: db_stmt
-               >> (DBCommand{ ln : lnp,
-                              cmd: cmdp,
-                              data: Vec::new() } )
-
-               
-               ))
+// parses statements separated by colons, like LET x = 1 : PRINT x
+named!(db_stmts<&[u8], Vec<DBStmt> >,
+       separated_nonempty_list!(sp!(tag!(":")), db_stmt)
        );
 
-// parses a whole program (list of commands)
-named!(db_prgm<&[u8], Vec<DBCommand> >,
-       ws!(do_parse!(
-           res: many1!(db_command)
-               >> eof!()
-               >> (res)
+// parses any command (a line number and its statements). Each
+// statement becomes its own command, sharing the line number.
+named!(db_command<&[u8], Vec<DBCommand> >,
+       do_parse!(
+           lnp: line_number
+               >> cmds: db_stmts
+               >> line_ending
+               >> (cmds.into_iter()
+                   .map(|cmdp| DBCommand{ ln : lnp,
+                                          cmd: cmdp,
+                                          data: Vec::new() })
+                   .collect())
                )
-           )
        );
 
+// a program that could not be parsed
+#[derive(Debug)]
+pub struct ParseError {
+    // where the line that could not be parsed starts
+    pub offset: usize,
+    pub line: String
+}
 
-pub fn parse_bytes(to_parse: &[u8]) -> Vec<DBCommand> {
-    
-    let res = match db_prgm(to_parse) {
-        IResult::Done(_, value) => value,
-        IResult::Error(err) => {
-            println!("Err {:?}",err);
-            panic!()
-        },
-        IResult::Incomplete(needed) => {
-            println!("Needed {:?}",needed);
-            panic!()
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line.is_empty() {
+            return write!(f, "The program has no lines");
         }
-    };
-
-    return res;
-    
+        return write!(f, "Could not parse line: {}", self.line);
+    }
 }
 
+// parses a whole program, a command at a time so that an error can
+// point at the line it is on
+pub fn try_parse_bytes(to_parse: &[u8]) -> Result<Vec<DBCommand>, ParseError> {
+    // every command ends with a line ending, including the last one
+    let mut program = to_parse.to_vec();
+    if !program.ends_with(b"\n") {
+        program.push(b'\n');
+    }
+
+    let mut res = Vec::new();
+    let mut rest = &program[..];
+    loop {
+        let start = rest.iter()
+            .position(|c| !(*c as char).is_whitespace())
+            .unwrap_or(rest.len());
+        rest = &rest[start..];
+        if rest.is_empty() {
+            break;
+        }
 
+        match db_command(rest) {
+            IResult::Done(remaining, cmds) => {
+                res.extend(cmds);
+                rest = remaining;
+            },
+            _ => {
+                let end = rest.iter().position(|&c| c == b'\n').unwrap_or(rest.len());
+                return Err(ParseError {
+                    offset: program.len() - rest.len(),
+                    line: String::from_utf8_lossy(&rest[..end]).trim_end().to_string()
+                });
+            }
+        }
+    }
 
+    if res.is_empty() {
+        return Err(ParseError { offset: 0, line: String::new() });
+    }
+    return Ok(res);
+}
+
+pub fn parse_bytes(to_parse: &[u8]) -> Vec<DBCommand> {
+    return try_parse_bytes(to_parse).unwrap_or_else(|e| panic!("{}", e));
+}
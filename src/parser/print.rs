@@ -0,0 +1,357 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+
+// turns parsed programs back into BASIC source, which parses to
+// the same commands again. The source is canonical: keywords are
+// upper case, words are separated by single spaces and only the
+// parentheses that change the meaning are kept.
+
+use parser::structs::{DBCommand, DBStmt, DBExpr, DBLetTarget, DBArrayDef, DBData,
+                      DBMatExpr, DBPrintSep};
+use parser::ast::{Expr, OpCode};
+
+// a program, one line per line number. Commands sharing a line
+// number are joined with colons.
+pub fn program_to_string(cmds: &[DBCommand]) -> String {
+    let mut to_r = String::new();
+    for (idx, cmd) in cmds.iter().enumerate() {
+        if idx > 0 && cmds[idx - 1].ln == cmd.ln {
+            to_r.push_str(" : ");
+        } else {
+            if idx > 0 {
+                to_r.push('\n');
+            }
+            to_r.push_str(&format!("{} ", cmd.ln));
+        }
+        to_r.push_str(&stmt_to_string(&cmd.cmd));
+    }
+
+    to_r.push('\n');
+    return to_r;
+}
+
+pub fn stmt_to_string(stmt: &DBStmt) -> String {
+    return match *stmt {
+        DBStmt::DEF { ref funcname, ref varname, ref expr } => {
+            format!("DEF {}({}) = {}", funcname, varname, db_expr_to_string(expr))
+        },
+
+        DBStmt::DIM { ref arrays } => {
+            // the arrays of a DIM are separated by spaces
+            let arrays: Vec<String> = arrays.iter().map(array_to_string).collect();
+            format!("DIM {}", arrays.join(" "))
+        },
+
+        DBStmt::FOR { ref varname, ref from_expr, ref to_expr, ref step_expr } => {
+            let mut to_r = format!("FOR {} = {} TO {}", varname,
+                                   db_expr_to_string(from_expr),
+                                   db_expr_to_string(to_expr));
+            if let Some(ref step) = **step_expr {
+                to_r.push_str(&format!(" STEP {}", db_expr_to_string(step)));
+            }
+            to_r
+        },
+
+        DBStmt::NEXT { ref varname } => format!("NEXT {}", varname),
+
+        DBStmt::WHILE { ref expr1, ref op, ref expr2 } => {
+            format!("WHILE {}", condition_to_string(expr1, op, expr2))
+        },
+
+        DBStmt::LOOP { until, ref expr1, ref op, ref expr2 } => {
+            format!("LOOP {} {}", if until { "UNTIL" } else { "WHILE" },
+                    condition_to_string(expr1, op, expr2))
+        },
+
+        DBStmt::GOSUB { lineno } => format!("GOSUB {}", lineno),
+        DBStmt::GOTO { lineno } => format!("GOTO {}", lineno),
+
+        DBStmt::ONGOTO { ref expr, ref linenos } => {
+            format!("ON {} GOTO {}", db_expr_to_string(expr), join_lines(linenos))
+        },
+
+        DBStmt::ONGOSUB { ref expr, ref linenos } => {
+            format!("ON {} GOSUB {}", db_expr_to_string(expr), join_lines(linenos))
+        },
+
+        DBStmt::IF { ref expr1, ref op, ref expr2, ref then_stmts, ref else_stmts } => {
+            let mut to_r = format!("IF {} THEN {}",
+                                   condition_to_string(expr1, op, expr2),
+                                   branch_to_string(then_stmts));
+            if !else_stmts.is_empty() {
+                to_r.push_str(&format!(" ELSE {}", branch_to_string(else_stmts)));
+            }
+            to_r
+        },
+
+        DBStmt::LET { ref target, ref expr } => {
+            format!("LET {} = {}", target_to_string(target), db_expr_to_string(expr))
+        },
+
+        DBStmt::PRINT { ref seq, ref seps } => {
+            if seq.is_empty() {
+                String::from("PRINT")
+            } else {
+                format!("PRINT {}", print_items_to_string(seq, seps))
+            }
+        },
+
+        DBStmt::PRINTUSING { ref format, ref seq, ref seps } => {
+            let mut to_r = format!("PRINT USING {}", quote(format));
+            if !seq.is_empty() {
+                to_r.push_str(&format!("; {}", print_items_to_string(seq, seps)));
+            }
+            to_r
+        },
+
+        DBStmt::DATA { ref seq } => {
+            let items: Vec<String> = seq.iter()
+                .map(|d| match *d {
+                    DBData::N(n) => format!("{}", n),
+                    DBData::S(ref s) => quote(s)
+                })
+                .collect();
+            format!("DATA {}", items.join(", "))
+        },
+
+        DBStmt::READ { ref varnames } => {
+            let targets: Vec<String> = varnames.iter().map(target_to_string).collect();
+            format!("READ {}", targets.join(", "))
+        },
+
+        DBStmt::MATREAD { ref arrays } => format!("MAT READ {}", arrays.join(", ")),
+        DBStmt::MATPRINT { ref arrays } => format!("MAT PRINT {}", arrays.join(", ")),
+
+        DBStmt::MAT { ref target, ref expr } => {
+            let expr = match *expr {
+                DBMatExpr::ZER => String::from("ZER"),
+                DBMatExpr::CON => String::from("CON"),
+                DBMatExpr::IDN => String::from("IDN"),
+                DBMatExpr::COPY(ref a) => a.clone(),
+                DBMatExpr::ADD(ref a, ref b) => format!("{} + {}", a, b),
+                DBMatExpr::SUB(ref a, ref b) => format!("{} - {}", a, b),
+                DBMatExpr::MUL(ref a, ref b) => format!("{} * {}", a, b)
+            };
+            format!("MAT {} = {}", target, expr)
+        },
+
+        DBStmt::REM { ref text } => {
+            if text.trim().is_empty() {
+                String::from("REM")
+            } else {
+                format!("REM {}", text.trim())
+            }
+        },
+
+        DBStmt::END | DBStmt::STOP | DBStmt::WEND | DBStmt::DO | DBStmt::RETURN => {
+            String::from(stmt.get_string_type())
+        }
+    };
+}
+
+pub fn db_expr_to_string(expr: &DBExpr) -> String {
+    return match *expr {
+        DBExpr::S(ref s) => quote(s),
+        DBExpr::E(ref e) => expr_to_string(e)
+    };
+}
+
+pub fn expr_to_string(expr: &Expr) -> String {
+    return match *expr {
+        Expr::O(ref e1, ref op, ref e2) => {
+            // operators are right-associative and have no
+            // precedence, so an operation on the left of another
+            // needs parentheses. One on the right keeps them unless
+            // the usual precedence would group it the same way, so
+            // that 2 * (3 + 4) doesn't read as 2 * 3 + 4.
+            let left = match *strip_parens(e1) {
+                Expr::O(..) => format!("({})", expr_to_string(e1)),
+                _ => expr_to_string(e1)
+            };
+            let right = match *strip_parens(e2) {
+                Expr::O(_, ref op2, _) if precedence(op2) <= precedence(op) =>
+                    format!("({})", expr_to_string(e2)),
+                _ => expr_to_string(e2)
+            };
+            let op = match *op {
+                OpCode::Add => "+",
+                OpCode::Sub => "-",
+                OpCode::Times => "*",
+                OpCode::Div => "/"
+            };
+            format!("{} {} {}", left, op, right)
+        },
+
+        Expr::N(n) => format!("{}", n),
+
+        Expr::D(d) => {
+            // keep the decimal point, or it would parse as a whole
+            // number
+            let s = format!("{}", d);
+            if s.contains('.') { s } else { format!("{}.0", s) }
+        },
+
+        Expr::V(ref name) => name.clone(),
+        Expr::A(ref def) => array_to_string(def),
+        Expr::E(ref e) => expr_to_string(e),
+        Expr::S(ref s) => quote(s),
+
+        Expr::F(ref name, ref args) => {
+            let args: Vec<String> = args.iter().map(expr_to_string).collect();
+            format!("{}({})", name, args.join(", "))
+        }
+    };
+}
+
+// how tightly an operator binds in the usual reading of arithmetic
+fn precedence(op: &OpCode) -> u32 {
+    return match *op {
+        OpCode::Add | OpCode::Sub => 1,
+        OpCode::Times | OpCode::Div => 2
+    };
+}
+
+fn strip_parens(expr: &Expr) -> &Expr {
+    return match *expr {
+        Expr::E(ref e) => strip_parens(e),
+        _ => expr
+    };
+}
+
+fn condition_to_string(expr1: &DBExpr, op: &str, expr2: &DBExpr) -> String {
+    return format!("{} {} {}", db_expr_to_string(expr1), op, db_expr_to_string(expr2));
+}
+
+// a branch that is only a GOTO is written as its line number
+fn branch_to_string(stmts: &[DBStmt]) -> String {
+    if let [DBStmt::GOTO { lineno }] = *stmts {
+        return format!("{}", lineno);
+    }
+
+    let stmts: Vec<String> = stmts.iter().map(stmt_to_string).collect();
+    return stmts.join(" : ");
+}
+
+fn print_items_to_string(seq: &[DBExpr], seps: &[DBPrintSep]) -> String {
+    let mut to_r = String::new();
+    for (idx, item) in seq.iter().enumerate() {
+        to_r.push_str(&db_expr_to_string(item));
+        match seps.get(idx) {
+            Some(&DBPrintSep::Comma) => to_r.push(','),
+            Some(&DBPrintSep::Semicolon) => to_r.push(';'),
+            None => {}
+        }
+        if idx + 1 < seq.len() {
+            to_r.push(' ');
+        }
+    }
+
+    return to_r;
+}
+
+fn array_to_string(def: &DBArrayDef) -> String {
+    let dims: Vec<String> = def.dims.iter().map(expr_to_string).collect();
+    return format!("{}({})", def.varname, dims.join(", "));
+}
+
+fn target_to_string(target: &DBLetTarget) -> String {
+    return match *target {
+        DBLetTarget::VAR(ref name) => name.clone(),
+        DBLetTarget::ARR(ref def) => array_to_string(def)
+    };
+}
+
+fn join_lines(linenos: &[u32]) -> String {
+    let lines: Vec<String> = linenos.iter().map(|l| l.to_string()).collect();
+    return lines.join(", ");
+}
+
+// a string literal, escaping what the parser unescapes
+fn quote(s: &str) -> String {
+    let mut to_r = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '\\' => to_r.push_str("\\\\"),
+            '\n' => to_r.push_str("\\n"),
+            '\r' => to_r.push_str("\\r"),
+            '\t' => to_r.push_str("\\t"),
+            c => to_r.push(c)
+        }
+    }
+    to_r.push('"');
+    return to_r;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use parser::parse_bytes;
+
+    fn format(program: &str) -> String {
+        return program_to_string(&parse_bytes(program.as_bytes()));
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let program = "5 REM every statement
+10 DEF FNA(X) = X * 2.5
+20 DIM A(3, 2) B$(4)
+30 LET A(1, 2) = FNA(3) / 2 : LET B$(1) = \"tab\\tslash\\\\\"
+40 FOR I = 1 TO 10
+50 PRINT I; \" \", LEN(B$(1));
+60 NEXT I
+70 WHILE I >= 0 : LET I = I - 1 : WEND
+80 DO : LOOP UNTIL I != 0
+90 ON I GOSUB 200, 200 : ON 1 GOTO 100
+100 IF I < 3 THEN 110 ELSE PRINT \"no\" : GOTO 110
+110 PRINT USING \"##.#\"; 2, 3 : PRINT
+120 DATA 1, -2.5, \"x\" : READ X, A(1, 1), C$
+130 MAT READ A : MAT PRINT A, A
+140 MAT A = ZER : MAT B = A * A : MAT A = B
+150 STOP
+200 RETURN
+";
+        let parsed = parse_bytes(program.as_bytes());
+        let printed = program_to_string(&parsed);
+        assert_eq!(printed, program);
+        assert_eq!(parse_bytes(printed.as_bytes()), parsed);
+    }
+
+    #[test]
+    fn canonical_test() {
+        assert_eq!(format("10 let x=(1+2)*(3-(4-1))+((5))\n20   rem  hi  \n30 end"),
+                   "10 LET X = (1 + 2) * ((3 - (4 - 1)) + 5)\n20 REM hi\n30 END\n");
+        assert_eq!(format("10 IF A$=\"y\" THEN GOTO 10 ELSE 20\n20 PRINT 1.50,2;\n"),
+                   "10 IF A$ = \"y\" THEN 10 ELSE 20\n20 PRINT 1.5, 2;\n");
+
+        // formatting twice changes nothing
+        let once = format("10 LET X = ((A - B) - C) / (D)\n");
+        assert_eq!(once, "10 LET X = ((A - B) - C) / D\n");
+        assert_eq!(format(&once), once);
+
+        // parentheses stay where the usual precedence would group
+        // the expression differently
+        for expr in &["2 * (3 + 4)", "1 - (2 - 3)", "1 + 2 * 3", "(1 + 2) * 3"] {
+            let line = format!("10 LET X = {}\n", expr);
+            assert_eq!(format(&line), line);
+        }
+        assert_eq!(format("10 LET X = 2 * 3 + 4\n"), "10 LET X = 2 * (3 + 4)\n");
+    }
+}
@@ -17,44 +17,93 @@
 // along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
 // 
 // < end copyright > 
+use std::fmt;
 use parser::ast;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum DBExpr {
     S(String),
     E(ast::Expr)
 }
 
-#[derive(Debug, Clone)]
+impl DBExpr {
+    pub fn is_string(&self) -> bool {
+        return match *self {
+            DBExpr::S(_) => true,
+            DBExpr::E(ref expr) => expr.is_string()
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum DBData {
+    N(f32),
+    S(String)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum DBLetTarget {
     VAR (String),
     ARR (DBArrayDef)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DBArrayDef {
     pub varname: String,
     pub dims: Vec<ast::Expr>
 }
 
-#[derive(Debug, Clone)]
+// what comes after an item of a PRINT statement
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum DBPrintSep {
+    Comma,
+    Semicolon
+}
+
+// the right side of a MAT assignment, like MAT A = B + C
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum DBMatExpr {
+    ZER,
+    CON,
+    IDN,
+    COPY (String),
+    ADD (String, String),
+    SUB (String, String),
+    MUL (String, String)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum DBStmt {
     DEF { funcname: String, varname: String, expr: DBExpr },
     DIM { arrays: Vec<DBArrayDef> },
     END,
+    STOP,
     FOR { varname: String, from_expr: DBExpr, to_expr: DBExpr,
           step_expr: Box<Option<DBExpr>> }, // boxing the step for memory layout
     NEXT { varname: String },
+    WHILE { expr1: DBExpr, op: String, expr2: DBExpr },
+    WEND,
+    DO,
+    LOOP { until: bool, expr1: DBExpr, op: String, expr2: DBExpr },
     GOSUB { lineno: u32 },
     RETURN,
     GOTO { lineno: u32},
+    ONGOTO { expr: DBExpr, linenos: Vec<u32> },
+    ONGOSUB { expr: DBExpr, linenos: Vec<u32> },
     IF {expr1: DBExpr, op: String, expr2: DBExpr,
-        lineno: u32},
+        then_stmts: Vec<DBStmt>, else_stmts: Vec<DBStmt>},
     LET { target: DBLetTarget, expr: DBExpr },
-    PRINT { seq: Vec<DBExpr> },
-    DATA {seq: Vec<f32> },
+    // seps[i] comes after seq[i], so there is one more separator
+    // than the gaps between items if the statement ends with one
+    PRINT { seq: Vec<DBExpr>, seps: Vec<DBPrintSep> },
+    PRINTUSING { format: String, seq: Vec<DBExpr>, seps: Vec<DBPrintSep> },
+    DATA {seq: Vec<DBData> },
     READ {varnames: Vec<DBLetTarget>},
-    REM
+    MATREAD { arrays: Vec<String> },
+    MATPRINT { arrays: Vec<String> },
+    MAT { target: String, expr: DBMatExpr },
+    // the text after REM, kept so the program can be printed again
+    REM { text: String }
 }
 
 impl DBStmt {
@@ -63,32 +112,59 @@ impl DBStmt {
             DBStmt::DEF {..} => "DEF",
             DBStmt::DIM {..} => "DIM",
             DBStmt::END {..} => "END",
+            DBStmt::STOP => "STOP",
             DBStmt::FOR {..} => "FOR",
             DBStmt::NEXT {..} => "NEXT",
+            DBStmt::WHILE {..} => "WHILE",
+            DBStmt::WEND => "WEND",
+            DBStmt::DO => "DO",
+            DBStmt::LOOP {..} => "LOOP",
             DBStmt::GOSUB {..} => "GOSUB",
             DBStmt::RETURN {..} => "RETURN",
             DBStmt::GOTO {..} => "GOTO",
+            DBStmt::ONGOTO {..} => "ON GOTO",
+            DBStmt::ONGOSUB {..} => "ON GOSUB",
             DBStmt::IF {..} => "IF",
             DBStmt::LET {..} => "LET",
             DBStmt::PRINT {..} => "PRINT",
+            DBStmt::PRINTUSING {..} => "PRINT USING",
             DBStmt::DATA {..} => "DATA",
             DBStmt::READ {..} => "READ",
+            DBStmt::MATREAD {..} => "MAT READ",
+            DBStmt::MATPRINT {..} => "MAT PRINT",
+            DBStmt::MAT {..} => "MAT",
             DBStmt::REM {..} => "REM"
         }
     }
+
+    // END and STOP both stop the program
+    pub fn is_end(&self) -> bool {
+        return matches!(*self, DBStmt::END | DBStmt::STOP);
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DBCommand {
     pub ln: u32,
     pub cmd: DBStmt,
-    pub data: Vec<u32>
+    pub data: Vec<DBData>
 }
 
 impl DBCommand {
-    pub fn add_data(&mut self, d: u32) {
+    pub fn add_data(&mut self, d: DBData) {
         self.data.push(d);
     }
 }
 
+// the line number and statement, plus the DATA values a READ gets
+impl fmt::Display for DBCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:?}", self.ln, self.cmd)?;
+        if !self.data.is_empty() {
+            write!(f, " <- {:?}", self.data)?;
+        }
+        return Ok(());
+    }
+}
+
 
@@ -0,0 +1,354 @@
+// < begin copyright >
+// Copyright Ryan Marcus 2017
+//
+// This file is part of basicaf.
+//
+// basicaf is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// basicaf is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with basicaf.  If not, see <http://www.gnu.org/licenses/>.
+//
+// < end copyright >
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+
+use parser;
+use parser::structs::{DBCommand, DBExpr, DBPrintSep, DBStmt};
+use ir;
+use compile;
+use interp::BFEnv;
+
+// the program being edited, and what it compiled to last
+struct Session {
+    // each line's text, by line number
+    lines: BTreeMap<u32, String>,
+    // cleared whenever the program changes
+    compiled: Option<String>,
+    // the statements run on their own so far, which are run again
+    // before each new one so that it sees the variables they set
+    immediate: Vec<DBCommand>,
+    // how much the statements in immediate print, which isn't
+    // shown again
+    immediate_output: usize,
+    opts: bool,
+    options: ir::Options
+}
+
+// printed after a statement run on its own, so that a statement that
+// stops the program early isn't run again
+const FINISHED: &str = "\u{1}";
+
+// an interactive session with stdin and stdout
+pub fn run(opts: bool, options: &ir::Options) -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    return serve(stdin.lock(), stdout.lock(), opts, options);
+}
+
+// reads commands until BYE or the end of the input. A running
+// program reads its input from the same place.
+pub fn serve<R: BufRead, W: Write>(mut input: R, mut output: W,
+                                   opts: bool, options: &ir::Options)
+                                   -> io::Result<()> {
+    let mut session = Session {
+        lines: BTreeMap::new(),
+        compiled: None,
+        immediate: Vec::new(),
+        immediate_output: 0,
+        opts: opts,
+        options: options.clone()
+    };
+
+    writeln!(output, "READY")?;
+    loop {
+        output.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // numbered lines edit the program
+        if line.starts_with(|c: char| c.is_ascii_digit()) {
+            if let Err(e) = session.edit(line) {
+                writeln!(output, "{}", e)?;
+            }
+            continue;
+        }
+
+        let mut words = line.splitn(2, char::is_whitespace);
+        let command = words.next().unwrap().to_uppercase();
+        let arg = words.next().unwrap_or("").trim();
+
+        let res = match command.as_str() {
+            "LIST" => {
+                for text in session.lines.values() {
+                    writeln!(output, "{}", text)?;
+                }
+                Ok(())
+            },
+
+            "NEW" => {
+                session.lines.clear();
+                session.compiled = None;
+                session.forget();
+                Ok(())
+            },
+
+            "RUN" => session.run(&mut input, &mut output),
+            "SAVE" => session.save(arg),
+            "LOAD" => session.load(arg),
+            "BYE" | "QUIT" => return output.flush(),
+
+            // anything else is a statement to run right away
+            _ => session.immediate(line, &mut input, &mut output)
+        };
+
+        if let Err(e) = res {
+            writeln!(output, "{}", e)?;
+        }
+        writeln!(output, "READY")?;
+    }
+}
+
+impl Session {
+    // adds, replaces or, for a line number on its own, deletes a line
+    fn edit(&mut self, line: &str) -> Result<(), String> {
+        edit_lines(&mut self.lines, line)?;
+        self.compiled = None;
+        return Ok(());
+    }
+
+    // runs a statement on its own, after the ones run before it
+    fn immediate<R: BufRead, W: Write>(&mut self, line: &str, input: &mut R,
+                                       output: &mut W) -> Result<(), String> {
+        // the statement is parsed as line 0, but errors show it as it
+        // was typed
+        let parsed = parser::try_parse_bytes(format!("0 {}", line).as_bytes())
+            .map_err(|mut e| {
+                e.line = line.to_string();
+                e.to_string()
+            })?;
+
+        let mut cmds = self.immediate.clone();
+        cmds.extend(parsed.iter().cloned());
+        cmds.push(DBCommand {
+            ln: 0,
+            cmd: DBStmt::PRINT { seq: vec![DBExpr::S(FINISHED.to_string())],
+                                 seps: vec![DBPrintSep::Semicolon] },
+            data: Vec::new()
+        });
+        let bf = compile::try_compile_commands(cmds, self.opts, &self.options)?;
+
+        let mut printed = Vec::new();
+        run_bf(bf, input, &mut printed)?;
+
+        let finished = printed.ends_with(FINISHED.as_bytes());
+        if finished {
+            let len = printed.len() - FINISHED.len();
+            printed.truncate(len);
+        }
+
+        output.write_all(&printed[self.immediate_output..])
+            .and_then(|_| writeln!(output))
+            .map_err(|e| e.to_string())?;
+
+        if finished {
+            self.immediate.extend(parsed);
+            self.immediate_output = printed.len();
+        }
+        return Ok(());
+    }
+
+    // forgets the statements run on their own
+    fn forget(&mut self) {
+        self.immediate.clear();
+        self.immediate_output = 0;
+    }
+
+    fn run<R: BufRead, W: Write>(&mut self, input: &mut R, output: &mut W)
+                                 -> Result<(), String> {
+        if self.lines.is_empty() {
+            return Err(String::from("There is no program to run"));
+        }
+
+        if self.compiled.is_none() {
+            let source = self.source();
+            self.compiled = Some(compile::try_compile_with(source, self.opts,
+                                                           &self.options)?);
+        }
+
+        let bf = self.compiled.clone().unwrap();
+        return execute(bf, input, output);
+    }
+
+    fn save(&self, path: &str) -> Result<(), String> {
+        if path.is_empty() {
+            return Err(String::from("SAVE needs a file name"));
+        }
+
+        return File::create(path)
+            .and_then(|mut f| f.write_all(self.source().as_bytes()))
+            .map_err(|e| format!("Could not save {}: {}", path, e));
+    }
+
+    // replaces the program with the one in a file
+    fn load(&mut self, path: &str) -> Result<(), String> {
+        if path.is_empty() {
+            return Err(String::from("LOAD needs a file name"));
+        }
+
+        let mut contents = String::new();
+        File::open(path)
+            .and_then(|mut f| f.read_to_string(&mut contents))
+            .map_err(|e| format!("Could not load {}: {}", path, e))?;
+
+        // the program is only replaced once every line has parsed
+        let mut lines = BTreeMap::new();
+        for line in contents.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            edit_lines(&mut lines, line)?;
+        }
+
+        self.lines = lines;
+        self.compiled = None;
+        self.forget();
+        return Ok(());
+    }
+
+    fn source(&self) -> String {
+        return self.lines.values()
+            .map(|text| format!("{}\n", text))
+            .collect();
+    }
+}
+
+// adds, replaces or, for a line number on its own, deletes a line
+// of a program
+fn edit_lines(lines: &mut BTreeMap<u32, String>, line: &str)
+              -> Result<(), String> {
+    if let Ok(ln) = line.parse::<u32>() {
+        lines.remove(&ln);
+        return Ok(());
+    }
+
+    let cmds = parser::try_parse_bytes(line.as_bytes())
+        .map_err(|e| e.to_string())?;
+    lines.insert(cmds[0].ln, line.to_string());
+    return Ok(());
+}
+
+// runs a compiled program, with a newline after its output
+fn execute<R: BufRead, W: Write>(bf: String, input: &mut R, output: &mut W)
+                                 -> Result<(), String> {
+    run_bf(bf, input, output)?;
+    return writeln!(output).map_err(|e| e.to_string());
+}
+
+// runs a compiled program
+fn run_bf<R: BufRead, W: Write>(bf: String, input: &mut R, output: &mut W)
+                                -> Result<(), String> {
+    return compile::catch_panic(|| BFEnv::new().run(bf, input, output))?
+        .map_err(|e| e.to_string());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    fn session(input: &str) -> String {
+        let mut output = Vec::new();
+        serve(input.as_bytes(), &mut output, true,
+              &ir::Options::default()).unwrap();
+        return String::from_utf8(output).unwrap();
+    }
+
+    #[test]
+    fn edit_test() {
+        let out = session("20 PRINT \"B\"\n10 PRINT \"A\"\n30 PRINT \"C\"\n\
+                           20\n10 PRINT \"Z\"\nLIST\nRUN\n");
+        assert_eq!(out, "READY\n10 PRINT \"Z\"\n30 PRINT \"C\"\nREADY\nZC\nREADY\n");
+    }
+
+    #[test]
+    fn immediate_test() {
+        let out = session("PRINT 3 + 4\nPRUNT 1\n10 PRONT\nbye\nPRINT 1\n");
+        assert_eq!(out, "READY\n7\nREADY\nCould not parse line: PRUNT 1\nREADY\n\
+                         Could not parse line: 10 PRONT\n");
+
+        // variables are kept until NEW, and what was printed before
+        // isn't printed again
+        let out = session("LET Y = 5\nPRINT Y\nLET Y = Y * 2 : PRINT \"X\"\n\
+                           PRINT Y\nNEW\nPRINT Y\n");
+        assert_eq!(out, "READY\n\nREADY\n5\nREADY\nX\nREADY\n10\nREADY\n\
+                         READY\n0\nREADY\n");
+
+        // a statement that stops early isn't run again
+        let out = session("LET Y = 1 : END : LET Y = 2\nPRINT Y\n");
+        assert_eq!(out, "READY\n\nREADY\n0\nREADY\n");
+    }
+
+    #[test]
+    fn run_test() {
+        let out = session("10 NEXT I\nRUN\nNEW\nLIST\nRUN\n");
+        assert_eq!(out, "READY\nNEXT statement without preceeding FOR loop!\n\
+                         READY\nREADY\nREADY\nThere is no program to run\nREADY\n");
+
+        // the compiled program is kept until the program changes
+        let mut s = Session {
+            lines: BTreeMap::new(),
+            compiled: None,
+            immediate: Vec::new(),
+            immediate_output: 0,
+            opts: true,
+            options: ir::Options::default()
+        };
+        let mut output = Vec::new();
+        s.edit("10 PRINT 1").unwrap();
+        s.run(&mut io::empty(), &mut output).unwrap();
+        assert!(s.compiled.is_some());
+        s.run(&mut io::empty(), &mut output).unwrap();
+        assert_eq!(output, b"1\n1\n");
+        s.edit("20 PRINT 2").unwrap();
+        assert!(s.compiled.is_none());
+    }
+
+    #[test]
+    fn save_load_test() {
+        let path = env::temp_dir().join("basicaf_repl_test.db");
+        let path = path.to_str().unwrap();
+
+        let out = session(&format!("10 PRINT \"HI\"\n20 END\nSAVE {}\n", path));
+        assert_eq!(out, "READY\nREADY\n");
+        assert_eq!(fs::read_to_string(path).unwrap(), "10 PRINT \"HI\"\n20 END\n");
+
+        let out = session(&format!("5 PRINT 1\nLOAD {}\nLIST\nRUN\n", path));
+        assert_eq!(out, "READY\nREADY\n10 PRINT \"HI\"\n20 END\nREADY\nHI\nREADY\n");
+        fs::remove_file(path).unwrap();
+
+        // a file that doesn't parse leaves the program alone
+        fs::write(path, "10 PRINT 1\n20 PRONT\n").unwrap();
+        let out = session(&format!("5 PRINT 5\nLOAD {}\nLIST\n", path));
+        assert_eq!(out, "READY\nCould not parse line: 20 PRONT\nREADY\n\
+                         5 PRINT 5\nREADY\n");
+        fs::remove_file(path).unwrap();
+
+        let out = session("LOAD /nonexistent/prog.db\n");
+        assert!(out.starts_with("READY\nCould not load /nonexistent/prog.db"));
+    }
+}